@@ -450,6 +450,102 @@ describe('po format', {timeout: 20_000}, () => {
     expect(relativeSpy).toHaveBeenCalled();
   });
 
+  it('writes the context of a message to msgctxt after the namespace', async () => {
+    filesystem.project.src['Menu.tsx'] = `
+    import {useExtracted} from 'next-intl';
+    function Menu() {
+      const t = useExtracted();
+      const tStore = useExtracted('store');
+      return <div>{t({message: 'Open', context: 'file'})} {tStore({message: 'Open', context: 'store'})}</div>;
+    }
+    `;
+    filesystem.project.messages = {
+      'de.po': `
+      #: src/Menu.tsx
+      msgctxt "|file"
+      msgid "nWoCia"
+      msgstr "Öffnen"
+      `
+    };
+
+    using compiler = createCompiler();
+    await compiler.extractAll();
+    await waitForWriteFileCalls(2);
+
+    const [[, de], [, en]] = vi
+      .mocked(fs.writeFile)
+      .mock.calls.toSorted(([a], [b]) => String(a).localeCompare(String(b)));
+    expect(en).toContain('msgctxt "|file"\nmsgid "nWoCia"\nmsgstr "Open"');
+    expect(en).toContain(
+      'msgctxt "store|store"\nmsgid "pS765l"\nmsgstr "Open"'
+    );
+    expect(de).toContain('msgctxt "|file"\nmsgid "nWoCia"\nmsgstr "Öffnen"');
+  });
+
+  it('escapes the context separator in namespaces of msgctxt', async () => {
+    filesystem.project.src['Menu.tsx'] = `
+    import {useExtracted} from 'next-intl';
+    function Menu() {
+      const t = useExtracted('ui|main');
+      return <div>{t({message: 'Open', context: 'file'})}</div>;
+    }
+    `;
+    filesystem.project.messages = {
+      'de.po': `
+      #: src/Menu.tsx
+      msgctxt "ui\\\\|main|file"
+      msgid "nWoCia"
+      msgstr "Öffnen"
+      `
+    };
+
+    using compiler = createCompiler();
+    await compiler.extractAll();
+    await waitForWriteFileCalls(2);
+
+    const [[, de], [, en]] = vi
+      .mocked(fs.writeFile)
+      .mock.calls.toSorted(([a], [b]) => String(a).localeCompare(String(b)));
+    expect(en).toContain(
+      'msgctxt "ui\\\\|main|file"\nmsgid "nWoCia"\nmsgstr "Open"'
+    );
+    expect(de).toContain(
+      'msgctxt "ui\\\\|main|file"\nmsgid "nWoCia"\nmsgstr "Öffnen"'
+    );
+  });
+
+  it('reports keys that are used for different messages across files', async () => {
+    const consoleError = vi
+      .spyOn(console, 'error')
+      .mockImplementation(() => {});
+    filesystem.project.src['A.tsx'] = `
+    import {useExtracted} from 'next-intl';
+    function A() {
+      const t = useExtracted();
+      return <div>{t({id: 'greeting', message: 'Hello'})}</div>;
+    }
+    `;
+    filesystem.project.src['B.tsx'] = `
+    import {useExtracted} from 'next-intl';
+    function B() {
+      const t = useExtracted();
+      return <div>{t({id: 'greeting', message: 'Hi'})}</div>;
+    }
+    `;
+    filesystem.project.messages = {};
+
+    using compiler = createCompiler();
+    await compiler.extractAll();
+    await waitForWriteFileCalls(1);
+
+    expect(consoleError).toHaveBeenCalledWith(
+      expect.stringContaining(
+        'The key "greeting" is used for different messages: "Hello" in src/A.tsx and "Hi" in src/B.tsx'
+      )
+    );
+    consoleError.mockRestore();
+  });
+
   it('stacks descriptions when the same message appears in multiple files', async () => {
     filesystem.project.src['A.tsx'] = `
     import {useExtracted} from 'next-intl';
@@ -13,9 +13,12 @@ export default class ExtractionCompiler implements Disposable {
       projectRoot?: string;
       saveDebounceMs?: number;
       sourceMap?: boolean;
+      shareKeyRegistry?: boolean;
     } = {}
   ) {
-    const extractor = opts.extractor ?? new MessageExtractor(opts);
+    const extractor =
+      opts.extractor ??
+      new MessageExtractor({...opts, plugin: config.extract.plugin});
     this.manager = new CatalogManager(config, {
       ...opts,
       extractor
@@ -9,6 +9,7 @@ import SourceFileWatcher, {
 } from '../source/SourceFileWatcher.js';
 import type {
   ExtractorConfig,
+  ExtractorKeyRegistry,
   ExtractorMessage,
   Locale,
   SourceMessage
@@ -20,6 +21,7 @@ import {
 } from '../utils.js';
 import CatalogLocales from './CatalogLocales.js';
 import CatalogPersister from './CatalogPersister.js';
+import KeyRegistryPersister from './KeyRegistryPersister.js';
 import SaveScheduler from './SaveScheduler.js';
 
 export default class CatalogManager implements Disposable {
@@ -29,6 +31,7 @@ export default class CatalogManager implements Disposable {
    * codec fields may merge from disk (e.g. flags).
    */
   private static readonly extractorOwnedAggregatorKeys = new Set<string>([
+    'context',
     'description',
     'id',
     'message',
@@ -62,6 +65,13 @@ export default class CatalogManager implements Disposable {
    */
   private messagesById: Map<string, ExtractorMessage> = new Map();
 
+  /**
+   * Derived from `sourceMessagesById` to detect key collisions across files.
+   * Rebuilt lazily after messages have changed.
+   */
+  private keyRegistry?: ExtractorKeyRegistry;
+  private lastWrittenKeyRegistry?: ExtractorKeyRegistry;
+
   /**
    * This potentially also includes outdated ones that were initially available,
    * but are not used anymore. This allows to restore them if they are used again.
@@ -79,6 +89,7 @@ export default class CatalogManager implements Disposable {
 
   // Cached instances
   private persister?: CatalogPersister;
+  private keyRegistryPersister?: KeyRegistryPersister;
   private codec?: ExtractorCodec;
   private catalogLocales?: CatalogLocales;
   private extractor: MessageExtractor;
@@ -98,6 +109,11 @@ export default class CatalogManager implements Disposable {
       projectRoot?: string;
       saveDebounceMs?: number;
       sourceMap?: boolean;
+      /**
+       * Writes the key registry so that loaders, which potentially run in
+       * other processes, can detect key collisions across files.
+       */
+      shareKeyRegistry?: boolean;
     }
   ) {
     this.config = config;
@@ -106,6 +122,9 @@ export default class CatalogManager implements Disposable {
     this.isDevelopment = opts.isDevelopment ?? false;
 
     this.extractor = opts.extractor;
+    if (opts.shareKeyRegistry) {
+      this.keyRegistryPersister = new KeyRegistryPersister(this.projectRoot);
+    }
 
     if (this.isDevelopment) {
       // We kick this off as early as possible, so we get notified about changes
@@ -314,7 +333,9 @@ export default class CatalogManager implements Disposable {
       const content = await fs.readFile(absoluteFilePath, 'utf8');
       let extraction: Awaited<ReturnType<typeof this.extractor.extract>>;
       try {
-        extraction = await this.extractor.extract(absoluteFilePath, content);
+        extraction = await this.extractor.extract(absoluteFilePath, content, {
+          keyRegistry: this.getKeyRegistry()
+        });
       } catch {
         return undefined;
       }
@@ -345,6 +366,10 @@ export default class CatalogManager implements Disposable {
       this.sourceMessagesByFile.delete(absoluteFilePath);
     }
 
+    if (affectedIds.size > 0) {
+      this.keyRegistry = undefined;
+    }
+
     // Clear this file's contribution from the reverse index, then re-insert
     // fresh rows and rebuild aggregates (messagesById) per touched id.
     for (const id of affectedIds) {
@@ -377,6 +402,22 @@ export default class CatalogManager implements Disposable {
     return changed;
   }
 
+  private getKeyRegistry(): ExtractorKeyRegistry {
+    if (!this.keyRegistry) {
+      this.keyRegistry = new Map();
+      for (const [id, sourceMessagesByFile] of this.sourceMessagesById) {
+        const [message] = sourceMessagesByFile.values().next().value ?? [];
+        if (!message) continue;
+        this.keyRegistry.set(id, {
+          message: message.message,
+          ...(message.context !== undefined && {context: message.context}),
+          path: message.reference.path
+        });
+      }
+    }
+    return this.keyRegistry;
+  }
+
   private groupSourceMessagesById(
     messages: Array<SourceMessage>
   ): Map<string, Array<SourceMessage>> {
@@ -402,20 +443,24 @@ export default class CatalogManager implements Disposable {
       return;
     }
 
+    this.reportKeyConflict(id, sourceMessages);
+
     const previousMessage = this.messagesById.get(id);
+    const {context} = sourceMessages[0];
     const aggregate: ExtractorMessage = {
       description: this.mergeDescriptions(sourceMessages),
       id,
       message: sourceMessages[0].message,
       references: sourceMessages
         .map((message) => message.reference)
-        .sort(compareReferences)
+        .sort(compareReferences),
+      ...(context !== undefined && {context})
     };
 
     if (previousMessage) {
       for (const key of Object.keys(previousMessage)) {
         // Preserve extra fields (e.g. from disk/codec) across rebuilds; the
-        // four core fields above are always recomputed from source messages.
+        // core fields above are always recomputed from source messages.
         if (
           !CatalogManager.extractorOwnedAggregatorKeys.has(key) &&
           aggregate[key] == null
@@ -428,6 +473,27 @@ export default class CatalogManager implements Disposable {
     this.messagesById.set(id, aggregate);
   }
 
+  /**
+   * Files are extracted in parallel during the initial scan, therefore the
+   * plugin can't detect all keys that are used for different messages, e.g.
+   * explicit IDs or keys kept via `keep` comments.
+   */
+  private reportKeyConflict(
+    id: string,
+    sourceMessages: Array<SourceMessage>
+  ): void {
+    const [first] = sourceMessages;
+    const conflicting = sourceMessages.find(
+      (message) =>
+        message.message !== first.message || message.context !== first.context
+    );
+    if (!conflicting) return;
+
+    console.error(
+      `❌ The key "${id}" is used for different messages: "${first.message}" in ${first.reference.path} and "${conflicting.message}" in ${conflicting.reference.path}. Use a different key for one of them.`
+    );
+  }
+
   private mergeDescriptions(
     messages: Array<SourceMessage>
   ): ExtractorMessage['description'] {
@@ -513,6 +579,15 @@ export default class CatalogManager implements Disposable {
     await this.saveLocale(this.config.extract.sourceLocale);
     const targetLocales = await this.getTargetLocales();
     await Promise.all(targetLocales.map((locale) => this.saveLocale(locale)));
+    await this.saveKeyRegistry();
+  }
+
+  private async saveKeyRegistry(): Promise<void> {
+    if (!this.keyRegistryPersister) return;
+    const keyRegistry = this.getKeyRegistry();
+    if (keyRegistry === this.lastWrittenKeyRegistry) return;
+    await this.keyRegistryPersister.write(keyRegistry);
+    this.lastWrittenKeyRegistry = keyRegistry;
   }
 
   private async saveLocale(locale: Locale): Promise<void> {
@@ -541,6 +616,7 @@ export default class CatalogManager implements Disposable {
         id: message.id,
         description: message.description,
         references: message.references,
+        context: message.context,
         message: isSourceLocale
           ? message.message
           : (localeMessage?.message ?? '')
@@ -0,0 +1,59 @@
+import fs from 'fs/promises';
+import fsPath from 'path';
+import type {ExtractorKeyRegistry, ExtractorRegisteredKey} from '../types.js';
+
+/**
+ * Shares the key registry of the catalog manager with loaders, which
+ * potentially run in other processes.
+ */
+export default class KeyRegistryPersister {
+  private filePath: string;
+  private cached?: {mtimeMs: number; registry: ExtractorKeyRegistry};
+
+  public constructor(projectRoot: string) {
+    this.filePath = fsPath.join(
+      projectRoot,
+      'node_modules/.cache/next-intl/keys.json'
+    );
+  }
+
+  /** Returns `undefined` if no registry was written yet. */
+  public async read(): Promise<ExtractorKeyRegistry | undefined> {
+    let mtimeMs: number;
+    try {
+      mtimeMs = (await fs.stat(this.filePath)).mtimeMs;
+    } catch {
+      return undefined;
+    }
+
+    if (this.cached?.mtimeMs === mtimeMs) {
+      return this.cached.registry;
+    }
+
+    try {
+      const content = await fs.readFile(this.filePath, 'utf8');
+      const registry = new Map(
+        Object.entries(
+          JSON.parse(content) as Record<string, ExtractorRegisteredKey>
+        )
+      );
+      this.cached = {mtimeMs, registry};
+      return registry;
+    } catch {
+      // Possibly read while being written, the next read will catch up
+      return this.cached?.registry;
+    }
+  }
+
+  public async write(registry: ExtractorKeyRegistry): Promise<void> {
+    try {
+      await fs.mkdir(fsPath.dirname(this.filePath), {recursive: true});
+      await fs.writeFile(
+        this.filePath,
+        JSON.stringify(Object.fromEntries(registry))
+      );
+    } catch (error) {
+      console.error(`❌ Failed to write key registry: ${error}`);
+    }
+  }
+}
@@ -16,7 +16,8 @@ export default async function extractMessages(params: ExtractorConfigInput) {
   const compiler = new ExtractionCompiler(config, {
     extractor: new MessageExtractor({
       isDevelopment: false,
-      projectRoot: getDefaultProjectRoot()
+      projectRoot: getDefaultProjectRoot(),
+      plugin: config.extract.plugin
     })
   });
   await compiler.extractAll();
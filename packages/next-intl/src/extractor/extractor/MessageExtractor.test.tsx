@@ -3,13 +3,14 @@ import MessageExtractor from './MessageExtractor.js';
 
 async function process(
   code: string,
-  opts?: Partial<ConstructorParameters<typeof MessageExtractor>[0]>
+  opts?: Partial<ConstructorParameters<typeof MessageExtractor>[0]>,
+  extractOpts?: Parameters<MessageExtractor['extract']>[2]
 ) {
   return await new MessageExtractor({
     isDevelopment: true,
     projectRoot: '/project',
     ...opts
-  }).extract('/project/test.tsx', code);
+  }).extract('/project/test.tsx', code, extractOpts);
 }
 
 it('can extract with source maps', async () => {
@@ -147,6 +148,70 @@ it('does not add a fallback message in production', async () => {
   `);
 });
 
+describe('key registry', () => {
+  const code = `import {useExtracted} from 'next-intl';
+
+    function Component() {
+      const t = useExtracted();
+      t('Edit');
+    }
+  `;
+
+  it('throws when a key collides with a message of another file', async () => {
+    const {messages} = await process(code);
+    const keyRegistry = new Map([
+      [messages[0].id, {message: 'Submit', path: 'src/Form.tsx'}]
+    ]);
+
+    await expect(process(code, {}, {keyRegistry})).rejects.toThrow(
+      `The key \`${messages[0].id}\` of "Edit" collides with the key of "Submit" in src/Form.tsx.`
+    );
+  });
+
+  it('lengthens colliding keys if configured', async () => {
+    const {messages} = await process(code);
+    const keyRegistry = new Map([
+      [messages[0].id, {message: 'Submit', path: 'src/Form.tsx'}]
+    ]);
+
+    const result = await process(
+      code,
+      {plugin: {keys: {lengthenCollisions: true}}},
+      {keyRegistry}
+    );
+    expect(result.messages[0].id).toHaveLength(7);
+    expect(result.messages[0].id.startsWith(messages[0].id)).toBe(true);
+  });
+
+  it('keeps the key of the previous message for bare keep comments', async () => {
+    const {messages} = await process(code);
+    const keyRegistry = new Map([
+      [messages[0].id, {message: 'Edit', path: 'test.tsx'}]
+    ]);
+
+    const result = await process(
+      code.replace("t('Edit')", "t(/* keep */ 'Edit!')"),
+      {},
+      {keyRegistry}
+    );
+    expect(result.messages[0]).toMatchObject({
+      id: messages[0].id,
+      message: 'Edit!',
+      previousMessage: 'Edit'
+    });
+  });
+
+  it('ignores messages of the same file', async () => {
+    const {messages} = await process(code);
+    const keyRegistry = new Map([
+      [messages[0].id, {message: 'Submit', path: 'test.tsx'}]
+    ]);
+
+    const result = await process(code, {}, {keyRegistry});
+    expect(result.messages[0].id).toBe(messages[0].id);
+  });
+});
+
 describe('error handling', () => {
   it('throws when source has parse error', async () => {
     await expect(
@@ -1,16 +1,30 @@
 import {createRequire} from 'module';
 import path from 'path';
 import {transform} from '@swc/core';
-import type {SourceMessage} from '../types.js';
-import {getDefaultProjectRoot, normalizePathToPosix} from '../utils.js';
+import type {
+  ExtractorKeyRegistry,
+  ExtractorPluginOptions,
+  ExtractorRegisteredKey,
+  SourceMessage
+} from '../types.js';
+import {
+  getDefaultProjectRoot,
+  getExtractionPattern,
+  normalizePathToPosix
+} from '../utils.js';
 import LRUCache from './LRUCache.js';
 
 const require = createRequire(import.meta.url);
 
+// E.g. `t(/* keep */ 'Hello!')`
+const BARE_KEEP_COMMENT = /\/\*\s*keep\s*\*\/|\/\/\s*keep\s*$/m;
+
 export default class MessageExtractor {
   private isDevelopment: boolean;
   private projectRoot: string;
   private sourceMap: boolean;
+  private plugin: ExtractorPluginOptions;
+  private extractionPattern: RegExp;
   private compileCache = new LRUCache<{
     messages: Array<SourceMessage>;
     code: string;
@@ -21,34 +35,102 @@ export default class MessageExtractor {
     isDevelopment?: boolean;
     projectRoot?: string;
     sourceMap?: boolean;
+    plugin?: ExtractorPluginOptions;
   }) {
     this.isDevelopment = opts.isDevelopment ?? false;
     this.projectRoot = opts.projectRoot ?? getDefaultProjectRoot();
     this.sourceMap = opts.sourceMap ?? false;
+    this.plugin = opts.plugin ?? {};
+    this.extractionPattern = getExtractionPattern(this.plugin);
   }
 
+  /**
+   * If a `keyRegistry` is provided, keys that collide with messages of other
+   * files are reported (or lengthened if `keys.lengthenCollisions` is set) and
+   * bare `keep` comments keep the key of the previous message.
+   */
   public async extract(
     absoluteFilePath: string,
-    source: string
+    source: string,
+    opts: {keyRegistry?: ExtractorKeyRegistry} = {}
   ): Promise<{
     messages: Array<SourceMessage>;
     code: string;
     map?: string;
   }> {
-    const cacheKey = [source, absoluteFilePath].join('!');
-    const cached = this.compileCache.get(cacheKey);
-    if (cached) return cached;
-
-    // Shortcut parsing if hook is not used. The Turbopack integration already
-    // pre-filters this, but for webpack this feature doesn't exist, so we need
-    // to do it here.
-    if (!source.includes('useExtracted') && !source.includes('getExtracted')) {
+    // Shortcut parsing if no extraction API is used. The Turbopack integration
+    // already pre-filters this, but for webpack this feature doesn't exist, so
+    // we need to do it here.
+    if (!this.extractionPattern.test(source)) {
       return {messages: [], code: source};
     }
 
     const filePath = normalizePathToPosix(
       path.relative(this.projectRoot, absoluteFilePath)
     );
+    // Bare `keep` comments are resolved against the previous messages of the
+    // file, therefore these are passed to the plugin upfront
+    const fileKeys =
+      opts.keyRegistry && BARE_KEEP_COMMENT.test(source)
+        ? Array.from(opts.keyRegistry).filter(
+            ([, registered]) => registered.path === filePath
+          )
+        : [];
+    const result = await this.compile(
+      source,
+      filePath,
+      fileKeys.length > 0 ? Object.fromEntries(fileKeys) : undefined
+    );
+    if (!opts.keyRegistry) return result;
+
+    // Only the registered keys that the file collides with are passed to the
+    // plugin, since the registry can be large
+    const collisions = this.getCollisions(
+      result.messages,
+      filePath,
+      opts.keyRegistry
+    );
+    if (collisions.length === 0) return result;
+    return this.compile(
+      source,
+      filePath,
+      Object.fromEntries([...fileKeys, ...collisions])
+    );
+  }
+
+  private getCollisions(
+    messages: Array<SourceMessage>,
+    filePath: string,
+    keyRegistry: ExtractorKeyRegistry
+  ): Array<[string, ExtractorRegisteredKey]> {
+    const collisions = new Map<string, ExtractorRegisteredKey>();
+    for (const message of messages) {
+      const registered = keyRegistry.get(message.id);
+      if (
+        registered &&
+        registered.path !== filePath &&
+        (registered.message !== message.message ||
+          registered.context !== message.context)
+      ) {
+        collisions.set(message.id, registered);
+      }
+    }
+    return Array.from(collisions);
+  }
+
+  private async compile(
+    source: string,
+    filePath: string,
+    registry?: Record<string, ExtractorRegisteredKey>
+  ): Promise<{
+    messages: Array<SourceMessage>;
+    code: string;
+    map?: string;
+  }> {
+    const cacheKey = [source, filePath, JSON.stringify(registry)].join('!');
+    const cached = this.compileCache.get(cacheKey);
+    if (cached) return cached;
+
     const result = await transform(source, {
       jsc: {
         target: 'esnext',
@@ -65,6 +147,8 @@ export default class MessageExtractor {
             [
               require.resolve('next-intl-swc-plugin-extractor'),
               {
+                ...this.plugin,
+                ...(registry && {keys: {...this.plugin.keys, registry}}),
                 isDevelopment: this.isDevelopment,
                 filePath
               }
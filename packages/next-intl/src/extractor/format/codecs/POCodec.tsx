@@ -19,6 +19,35 @@ export default defineCodec(() => {
   // Move all parts before the last dot to msgctxt
   const NAMESPACE_SEPARATOR = '.';
 
+  // The context of a message follows the namespace in msgctxt, e.g.
+  // `ui|file` or `|file` without a namespace. Occurrences in the namespace
+  // are escaped, so the first unescaped separator ends the namespace.
+  const CONTEXT_SEPARATOR = '|';
+  const ESCAPE_CHARACTER = '\\';
+
+  function escapeNamespace(namespace: string) {
+    return namespace.replaceAll(/[\\|]/g, (char) => ESCAPE_CHARACTER + char);
+  }
+
+  function splitMsgctxt(msgctxt: string) {
+    let namespace = '';
+    for (let index = 0; index < msgctxt.length; index++) {
+      const char = msgctxt[index];
+      if (char === ESCAPE_CHARACTER && index + 1 < msgctxt.length) {
+        namespace += msgctxt[index + 1];
+        index++;
+      } else if (char === CONTEXT_SEPARATOR) {
+        return {
+          namespace,
+          context: msgctxt.slice(index + CONTEXT_SEPARATOR.length)
+        };
+      } else {
+        namespace += char;
+      }
+    }
+    return {namespace, context: undefined};
+  }
+
   // Metadata is stored so it can be retained when writing
   const metadataByLocale = new Map();
 
@@ -33,19 +62,32 @@ export default defineCodec(() => {
         const {extractedComments, msgctxt, msgid, msgstr, references, ...rest} =
           msg;
 
+        const {namespace, context: messageContext} =
+          msgctxt !== undefined
+            ? splitMsgctxt(msgctxt)
+            : {namespace: undefined, context: undefined};
+
         return {
           ...rest,
-          id: msgctxt ? [msgctxt, msgid].join(NAMESPACE_SEPARATOR) : msgid,
+          id: namespace ? [namespace, msgid].join(NAMESPACE_SEPARATOR) : msgid,
           message: msgstr,
           description: extractedComments ?? [],
-          references: references ?? []
+          references: references ?? [],
+          ...(messageContext !== undefined && {context: messageContext})
         };
       });
     },
 
     encode(messages, context) {
       const encodedMessages = getSortedMessages(messages).map((msg) => {
-        const {description = [], id, message, references, ...rest} = msg;
+        const {
+          context: messageContext,
+          description = [],
+          id,
+          message,
+          references,
+          ...rest
+        } = msg;
 
         const lastDotIndex = id.lastIndexOf(NAMESPACE_SEPARATOR);
         const hasNamespace = id.includes(NAMESPACE_SEPARATOR);
@@ -53,6 +95,13 @@ export default defineCodec(() => {
         const msgid = hasNamespace
           ? id.slice(lastDotIndex + NAMESPACE_SEPARATOR.length)
           : id;
+        const namespace = hasNamespace
+          ? escapeNamespace(id.slice(0, lastDotIndex))
+          : undefined;
+        const msgctxt =
+          messageContext !== undefined
+            ? [namespace ?? '', messageContext].join(CONTEXT_SEPARATOR)
+            : namespace;
 
         // Path-only refs (no `:line`), unique paths
         const pathOnlyRefs: Array<{path: string}> = [
@@ -63,7 +112,7 @@ export default defineCodec(() => {
           msgid,
           msgstr: message,
           ...(description.length > 0 && {extractedComments: description}),
-          ...(hasNamespace && {msgctxt: id.slice(0, lastDotIndex)}),
+          ...(msgctxt !== undefined && {msgctxt}),
           ...(pathOnlyRefs.length > 0 && {references: pathOnlyRefs}),
           ...rest
         };
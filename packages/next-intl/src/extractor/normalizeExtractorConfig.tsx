@@ -1,5 +1,9 @@
 import {throwError, warn} from '../plugin/utils.js';
-import type {ExtractorConfig, ExtractorConfigInput} from './types.js';
+import type {
+  ExtractorConfig,
+  ExtractorConfigInput,
+  ExtractorPluginOptions
+} from './types.js';
 
 function stripTrailingSlash(dirPath: string): string {
   if (dirPath.endsWith('/')) {
@@ -31,6 +35,7 @@ export default function normalizeExtractorConfig(
 
   let extractPath: string | undefined;
   let sourceLocale: string | undefined;
+  const plugin: ExtractorPluginOptions = {};
 
   if (extract !== undefined && extract !== true) {
     if (extract.sourceLocale) {
@@ -43,6 +48,22 @@ export default function normalizeExtractorConfig(
     if (extract.path) {
       extractPath = stripTrailingSlash(extract.path);
     }
+
+    if (extract.hooks) {
+      plugin.hooks = extract.hooks;
+    }
+
+    if (extract.keys) {
+      plugin.keys = extract.keys;
+    }
+
+    if (extract.normalize) {
+      plugin.normalize = extract.normalize;
+    }
+
+    if (extract.ids) {
+      plugin.ids = extract.ids;
+    }
   }
 
   const locales = input.messages.locales;
@@ -84,7 +105,8 @@ export default function normalizeExtractorConfig(
       locales,
       path: extractPath,
       sourceLocale,
-      srcPath
+      srcPath,
+      plugin
     },
     messages: {
       format: input.messages.format,
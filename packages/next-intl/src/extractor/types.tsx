@@ -15,6 +15,12 @@ export type SourceMessage = {
   id: string;
   message: string;
   description: string | null;
+  /** Distinguishes the same message with different meanings, like `msgctxt` in PO. */
+  context?: string;
+  /** The module-scope constants the message was composed of. */
+  constants?: Array<string>;
+  /** The text before a change that kept the key via a `keep` comment. */
+  previousMessage?: string;
   reference: ExtractorMessageReference;
 };
 
@@ -32,10 +38,91 @@ export type ExtractorMessage = {
    * Empty when the catalog format does not store references or none are known.
    */
   references: Array<ExtractorMessageReference>;
+  /** Distinguishes the same message with different meanings (e.g. part of `msgctxt` in PO). */
+  context?: string;
   /** Allows for additional properties like .po flags to be read and later written. */
   [key: string]: unknown;
 };
 
+/** An export that is treated like `useExtracted` or `getExtracted`. */
+export type ExtractorHookSource = {
+  /** The module specifier the hook is imported from (e.g. `@/i18n`). */
+  source: string;
+  /** The exported name of the hook (e.g. `useAppExtracted`). */
+  name: string;
+  /** The translation hook the calls are compiled to. */
+  type: 'useTranslations' | 'getTranslations';
+  /** Defaults to `next-intl` or `next-intl/server`. */
+  target?: string;
+};
+
+/** Options that are passed on to the SWC plugin that extracts messages. */
+export type ExtractorPluginOptions = {
+  /**
+   * Additional modules that export extracted hooks, e.g. a project-level
+   * re-export of `useExtracted` from `@/i18n`.
+   */
+  hooks?: Array<ExtractorHookSource>;
+  /** How keys are generated from messages. */
+  keys?: {
+    /** Defaults to `sha512`. */
+    algorithm?: 'sha256' | 'sha512';
+    /** The number of characters of the hash that are used (defaults to 6). */
+    length?: number;
+    /** Prepended to every generated key (e.g. `app_`). */
+    prefix?: string;
+    /**
+     * `hash` generates opaque keys (e.g. `OpKKos`), while `slug` includes
+     * words of the message (e.g. `checkout_total_a1b2`).
+     */
+    mode?: 'hash' | 'slug';
+    /**
+     * Resolves collisions by lengthening the key of the newer message instead
+     * of reporting an error.
+     */
+    lengthenCollisions?: boolean;
+    /**
+     * Includes the description of a message in its key, so that the same text
+     * with different descriptions results in different keys.
+     */
+    hashDescription?: boolean;
+  };
+  /**
+   * How messages are canonicalized before their key is generated. The
+   * canonical text is also the one that is extracted.
+   */
+  normalize?: {
+    /** Composes characters like `e` with a combining accent to `é`. */
+    nfc?: boolean;
+    /** Replaces `\r\n` and `\r` with `\n`. */
+    newlines?: boolean;
+    /** Removes the indentation shared by multi-line template literals. */
+    dedent?: boolean;
+    /** Replaces every sequence of whitespace with a single space. */
+    collapseWhitespace?: boolean;
+    /** Removes leading and trailing whitespace. */
+    trim?: boolean;
+  };
+  /** Restrictions for explicit IDs like `t({id: 'greeting', ...})`. */
+  ids?: {
+    /** Defaults to 100. */
+    maxLength?: number;
+    /** A regular expression that IDs need to match, e.g. `^[a-z][a-zA-Z]*$`. */
+    pattern?: string;
+  };
+};
+
+/** An extracted message that generated keys are checked against. */
+export type ExtractorRegisteredKey = {
+  message: string;
+  context?: string;
+  /** Relative to the project root. */
+  path: string;
+};
+
+/** Messages of all files by ID, used to detect key collisions across files. */
+export type ExtractorKeyRegistry = Map<string, ExtractorRegisteredKey>;
+
 /**
  * External extractor configuration (Next.js plugin, `extractMessages`).
  */
@@ -61,12 +148,12 @@ export type ExtractorConfigInput = {
    */
   extract?:
     | true
-    | {
+    | ({
         /** Defaults to `messages.path` when it is a single path. */
         path?: string;
         /** @deprecated Prefer `messages.sourceLocale`. */
         sourceLocale?: string;
-      };
+      } & ExtractorPluginOptions);
 };
 
 /** Normalized config used internally after `normalizeExtractorConfig`. */
@@ -76,6 +163,7 @@ export type ExtractorConfig = {
     path: string;
     sourceLocale: string;
     srcPath: string | Array<string>;
+    plugin?: ExtractorPluginOptions;
   };
   messages: {
     format: MessagesFormat;
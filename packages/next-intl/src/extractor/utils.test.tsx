@@ -1,5 +1,9 @@
 import {describe, expect, it, vi} from 'vitest';
-import {getSortedMessages, setNestedProperty} from './utils.js';
+import {
+  getExtractionPattern,
+  getSortedMessages,
+  setNestedProperty
+} from './utils.js';
 
 describe('getSortedMessages', () => {
   it('sorts by reference path', () => {
@@ -115,3 +119,22 @@ describe('setNestedProperty', () => {
     expect(({} as Record<string, unknown>).b).toBeUndefined();
   });
 });
+
+describe('getExtractionPattern', () => {
+  it('matches all extraction APIs', () => {
+    const pattern = getExtractionPattern();
+    expect(pattern.test('const t = useExtracted();')).toBe(true);
+    expect(pattern.test('const t = await getExtracted();')).toBe(true);
+    expect(pattern.test('<Extracted>Hello</Extracted>')).toBe(true);
+    expect(pattern.test("const label = msg('Hello');")).toBe(true);
+    expect(pattern.test('defineMessages({})')).toBe(true);
+    expect(pattern.test('const message = getMessage();')).toBe(false);
+  });
+
+  it('matches configured hooks', () => {
+    const pattern = getExtractionPattern({
+      hooks: [{source: '@/i18n', name: 'useLabels', type: 'useTranslations'}]
+    });
+    expect(pattern.test('const t = useLabels();')).toBe(true);
+  });
+});
@@ -3,7 +3,8 @@ import {warn} from '../plugin/utils.js';
 import type {
   ExtractorConfig,
   ExtractorMessage,
-  ExtractorMessageReference
+  ExtractorMessageReference,
+  ExtractorPluginOptions
 } from './types.js';
 
 export function normalizePathToPosix(filePath: string): string {
@@ -24,6 +25,30 @@ export function isForbiddenObjectKey(key: string): boolean {
   return FORBIDDEN_OBJECT_KEYS.has(key);
 }
 
+const EXTRACTION_MARKERS = [
+  'useExtracted',
+  'getExtracted',
+  'Extracted',
+  'msg',
+  'defineMessages'
+];
+
+/**
+ * Matches source files that may contain messages to extract, i.e. that
+ * reference one of the extraction APIs (incl. `<Extracted>` and message
+ * descriptors) or a configured hook.
+ */
+export function getExtractionPattern(
+  plugin: ExtractorPluginOptions = {}
+): RegExp {
+  const markers = [
+    ...EXTRACTION_MARKERS,
+    ...(plugin.hooks ?? []).map((hook) => hook.name)
+  ].map((marker) => marker.replace(/[$]/g, '\\$&'));
+  // Lookarounds aren't supported by Turbopack, therefore word boundaries
+  return new RegExp(`\\b(${markers.join('|')})\\b`);
+}
+
 export function hasLocalesToExtract(
   config: Pick<ExtractorConfig, 'extract'>
 ): boolean {
@@ -1,3 +1,4 @@
+import KeyRegistryPersister from '../../extractor/catalog/KeyRegistryPersister.js';
 import MessageExtractor from '../../extractor/extractor/MessageExtractor.js';
 import type {ExtractorConfig} from '../../extractor/types.js';
 import type {TurbopackLoaderContext} from '../types.js';
@@ -8,11 +9,15 @@ import type {TurbopackLoaderContext} from '../types.js';
 // catalogs) is initialized separately in createNextIntlPlugin.
 let extractor: MessageExtractor | undefined;
 
+// Written by the ExtractionCompiler to detect key collisions across files
+let keyRegistryPersister: KeyRegistryPersister | undefined;
+
 export default function extractionLoader(
   this: TurbopackLoaderContext<ExtractorConfig>,
   source: string
 ) {
   const callback = this.async();
+  const options = this.getOptions();
   const projectRoot = this.rootContext;
 
   // Avoid rollup's `replace` plugin to compile this away
@@ -22,12 +27,19 @@ export default function extractionLoader(
     extractor = new MessageExtractor({
       isDevelopment,
       projectRoot,
-      sourceMap: this.sourceMap
+      sourceMap: this.sourceMap,
+      plugin: options.extract.plugin
     });
   }
 
-  extractor
-    .extract(this.resourcePath, source)
+  const messageExtractor = extractor;
+
+  keyRegistryPersister ??= new KeyRegistryPersister(projectRoot);
+  keyRegistryPersister
+    .read()
+    .then((keyRegistry) =>
+      messageExtractor.extract(this.resourcePath, source, {keyRegistry})
+    )
     .then((result) => {
       callback(null, result.code, result.map);
     })
@@ -34,7 +34,8 @@ export default function initExtractionCompiler(
   runOnce(() => {
     compiler = new ExtractionCompiler(extractorConfig, {
       isDevelopment,
-      projectRoot: process.cwd()
+      projectRoot: process.cwd(),
+      shareKeyRegistry: true
     });
 
     // Fire-and-forget: Start extraction, don't block config return.
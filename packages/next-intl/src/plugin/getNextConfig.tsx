@@ -12,6 +12,7 @@ import {getFormatExtension} from '../extractor/format/index.js';
 import {normalizeMessagesCatalogPaths} from '../extractor/normalizeExtractorConfig.js';
 import SourceFileFilter from '../extractor/source/SourceFileFilter.js';
 import type {CatalogLoaderConfig, ExtractorConfig} from '../extractor/types.js';
+import {getExtractionPattern} from '../extractor/utils.js';
 import {isDevelopmentOrNextBuild} from './config.js';
 import {hasStableTurboConfig, isNextJs16OrHigher} from './nextFlags.js';
 import type {PluginConfig} from './types.js';
@@ -204,7 +205,7 @@ export default function getNextConfig(
         condition: {
           // We don't filter for `path` here to allow transformation
           // of `useExtracted` calls in external packages (e.g. monorepos)
-          content: /(useExtracted|getExtracted)/
+          content: getExtractionPattern(extractorConfig?.extract.plugin)
         }
       });
     }
@@ -22,6 +22,12 @@ export {
   useMessages
 } from 'use-intl/react';
 export {_useExtracted as useExtracted} from 'use-intl/react';
+export {default as Extracted} from '../shared/Extracted.js';
+export {
+  _msg as msg,
+  _defineMessages as defineMessages
+} from 'use-intl/core';
+export type {_MessageDescriptor as MessageDescriptor} from 'use-intl/core';
 
 // eslint-disable-next-line @typescript-eslint/no-unsafe-function-type
 function callHook(name: string, hook: Function) {
@@ -15,6 +15,12 @@ export {default as useTimeZone} from './useTimeZone.js';
 export {default as useMessages} from './useMessages.js';
 export {default as NextIntlClientProvider} from './NextIntlClientProviderServer.js';
 export {default as useExtracted} from './useExtracted.js';
+export {default as Extracted} from '../shared/Extracted.js';
+export {
+  _msg as msg,
+  _defineMessages as defineMessages
+} from 'use-intl/core';
+export type {_MessageDescriptor as MessageDescriptor} from 'use-intl/core';
 
 // Everything from `core`
 export * from 'use-intl/core';
@@ -5,6 +5,13 @@ import getServerTranslator from './getServerTranslator.js';
 
 type Return = ReturnType<typeof getServerTranslator>;
 
+// Descriptors from `msg` and `defineMessages` carry the message in a property
+function getMessage(messageOrDescriptor: unknown) {
+  return typeof messageOrDescriptor === 'string'
+    ? messageOrDescriptor
+    : (messageOrDescriptor as {message: string}).message;
+}
+
 // Note: This API is usually compiled into `useTranslations`,
 // but there is some fallback handling which allows this hook
 // to still work when not being compiled.
@@ -32,7 +39,7 @@ function getServerExtractorImpl(
       values,
       formats,
       // @ts-expect-error -- Secret fallback parameter
-      message
+      getMessage(message)
     );
   }
 
@@ -44,7 +51,7 @@ function getServerExtractorImpl(
       values,
       formats,
       // @ts-expect-error -- Secret fallback parameter
-      message
+      getMessage(message)
     );
   };
 
@@ -56,7 +63,7 @@ function getServerExtractorImpl(
       values,
       formats,
       // @ts-expect-error -- Secret fallback parameter
-      message
+      getMessage(message)
     );
   };
 
@@ -0,0 +1,24 @@
+import type {ReactNode} from 'react';
+
+type Props = {
+  /** The message in the source locale, e.g. `Hello <b>{name}</b>`. */
+  children: ReactNode;
+  id?: string;
+  /** Description for translators and tooling. */
+  description?: string;
+  /** Distinguishes the same message with different meanings. */
+  context?: string;
+};
+
+// Note: This component is usually compiled into a `t.rich` call of
+// the enclosing component, but renders its children as a fallback
+// when not being compiled (e.g. in tests or Storybook).
+export default function Extracted({children}: Props) {
+  if (process.env.NODE_ENV === 'production') {
+    throw new Error(
+      '[next-intl] `<Extracted>` was rendered in production without compilation. Include modules that render `<Extracted>` in `srcPath` and use `transpilePackages` for 3rd-party packages.'
+    );
+  }
+
+  return children;
+}
@@ -0,0 +1,109 @@
+use regex::Regex;
+use serde::Deserialize;
+
+use crate::NAMESPACE_SEPARATOR;
+
+/// Restrictions for explicit IDs like `t({id: 'greeting', ...})`, in
+/// addition to the built-in rules.
+#[derive(Debug, Clone, Deserialize)]
+#[serde(rename_all = "camelCase", default)]
+pub struct IdConfig {
+    pub max_length: usize,
+
+    /// A regular expression that IDs need to match, e.g. `^[a-z][a-zA-Z]*$`
+    pub pattern: Option<String>,
+}
+
+impl Default for IdConfig {
+    fn default() -> Self {
+        Self {
+            max_length: 100,
+            pattern: None,
+        }
+    }
+}
+
+pub struct IdValidator {
+    max_length: usize,
+    pattern: Option<Regex>,
+}
+
+impl IdValidator {
+    /// A `pattern` that isn't a valid regular expression is reported to
+    /// `errors` and ignored.
+    pub fn new(config: &IdConfig, errors: &mut Vec<String>) -> Self {
+        let pattern = config
+            .pattern
+            .as_deref()
+            .and_then(|pattern| match Regex::new(pattern) {
+                Ok(pattern) => Some(pattern),
+                Err(error) => {
+                    errors.push(format!(
+                        "The pattern `{pattern}` from `ids.pattern` is invalid: {error}"
+                    ));
+                    None
+                }
+            });
+        Self {
+            max_length: config.max_length,
+            pattern,
+        }
+    }
+
+    /// Returns a description of the problem if an explicit ID can't be used.
+    pub fn validate(&self, id: &str) -> Result<(), String> {
+        self.validate_key(id)?;
+
+        if let Some(pattern) = &self.pattern {
+            if !pattern.is_match(id) {
+                return Err(format!(
+                    "The ID `{id}` doesn't match the pattern `{}` from `ids.pattern`.",
+                    pattern.as_str()
+                ));
+            }
+        }
+
+        Ok(())
+    }
+
+    /// Checks the rules that apply to all keys, e.g. a generated key that is
+    /// referenced by a `keep-id` comment, which doesn't need to match the
+    /// `pattern` of explicit IDs.
+    pub fn validate_key(&self, id: &str) -> Result<(), String> {
+        if id.trim().is_empty() {
+            return Err("IDs can't be empty.".to_string());
+        }
+
+        // Namespaces are separated by dots, which the runtime resolves as
+        // nested messages
+        if id.contains(NAMESPACE_SEPARATOR) {
+            return Err(format!(
+                "The ID `{id}` can't contain `{NAMESPACE_SEPARATOR}`, as it's used to \
+                 separate namespaces. Use a namespace instead, e.g. `useExtracted('carousel')`."
+            ));
+        }
+
+        if let Some(char) = invalid_key_char(id) {
+            return Err(format!(
+                "The ID `{id}` contains `{char}`, but IDs can only consist of letters, digits, \
+                 `_` and `-`."
+            ));
+        }
+
+        if id.chars().count() > self.max_length {
+            return Err(format!(
+                "The ID `{id}` is longer than {} characters.",
+                self.max_length
+            ));
+        }
+
+        Ok(())
+    }
+}
+
+/// Finds a character that can't be used in keys, which can only consist of
+/// letters, digits, `_` and `-`.
+pub fn invalid_key_char(key: &str) -> Option<char> {
+    key.chars()
+        .find(|char| !char.is_alphanumeric() && *char != '_' && *char != '-')
+}
@@ -0,0 +1,269 @@
+use rustc_hash::{FxHashMap, FxHashSet};
+use swc_atoms::{Atom, Wtf8Atom};
+use swc_ecma_ast::*;
+use swc_ecma_utils::find_pat_ids;
+use swc_ecma_visit::{Visit, VisitWith};
+
+use crate::{module_source, static_eval::unwrap_expr};
+
+/// Functions declared in the same module that can receive a translator, e.g.
+/// `function renderRow(t, item) {...}` called as `renderRow(t, item)`.
+#[derive(Debug, Default)]
+pub struct HelperFunctions {
+    /// Parameter bindings of each helper by position (`None` for patterns
+    /// other than plain identifiers).
+    pub params: FxHashMap<Id, Vec<Option<Id>>>,
+
+    /// Helpers in declaration order.
+    pub order: Vec<Id>,
+}
+
+impl HelperFunctions {
+    /// Finds the helpers that are called with a binding that may hold a
+    /// translator. Starting from the hooks in `hooks`, bindings derived from
+    /// them are tracked until no further ones are found. `is_hook` resolves
+    /// hooks destructured from `require('...')` and `import('...')`.
+    pub fn collect<N>(
+        node: &N,
+        hooks: impl IntoIterator<Item = Id>,
+        is_hook: &dyn Fn(&Wtf8Atom, &str) -> bool,
+    ) -> Self
+    where
+        N: for<'a> VisitWith<HelperCollector<'a>>,
+    {
+        let mut collector = HelperCollector {
+            is_hook,
+            declarations: Default::default(),
+            declared: Default::default(),
+            candidates: hooks.into_iter().collect(),
+            this_candidates: Default::default(),
+            receiving: Default::default(),
+        };
+
+        // Translators can be passed on before they are defined in the module
+        loop {
+            let len = collector.len();
+            node.visit_with(&mut collector);
+            if collector.len() == len {
+                break;
+            }
+        }
+
+        let mut helpers = HelperFunctions::default();
+        for (id, params) in collector.declarations {
+            if collector.receiving.contains(&id) && !helpers.params.contains_key(&id) {
+                helpers.order.push(id.clone());
+                helpers.params.insert(id, params);
+            }
+        }
+        helpers
+    }
+}
+
+pub struct HelperCollector<'a> {
+    is_hook: &'a dyn Fn(&Wtf8Atom, &str) -> bool,
+    declarations: Vec<(Id, Vec<Option<Id>>)>,
+    declared: FxHashMap<Id, usize>,
+
+    /// Bindings that may hold a translator or a hook.
+    candidates: FxHashSet<Id>,
+
+    /// Properties of `this` that may hold a translator, e.g. `this.t = t`.
+    this_candidates: FxHashSet<Atom>,
+
+    /// Helpers that are called with a candidate.
+    receiving: FxHashSet<Id>,
+}
+
+impl HelperCollector<'_> {
+    fn len(&self) -> (usize, usize, usize) {
+        (
+            self.declarations.len(),
+            self.candidates.len(),
+            self.this_candidates.len(),
+        )
+    }
+
+    fn references_candidate(&self, expr: &Expr) -> bool {
+        let mut finder = CandidateFinder {
+            candidates: &self.candidates,
+            this_candidates: &self.this_candidates,
+            found: false,
+        };
+        expr.visit_with(&mut finder);
+        finder.found
+    }
+
+    fn add_candidates(&mut self, pat: &Pat) {
+        let ids: Vec<Id> = find_pat_ids(pat);
+        self.candidates.extend(ids);
+    }
+
+    fn declare(&mut self, id: Id, params: Vec<Option<Id>>) {
+        if !self.declared.contains_key(&id) {
+            self.declared.insert(id.clone(), self.declarations.len());
+            self.declarations.push((id, params));
+        }
+    }
+}
+
+fn param_ids<'a>(pats: impl Iterator<Item = &'a Pat>) -> Vec<Option<Id>> {
+    pats.map(|pat| pat.as_ident().map(|ident| ident.to_id()))
+        .collect()
+}
+
+impl Visit for HelperCollector<'_> {
+    fn visit_fn_decl(&mut self, node: &FnDecl) {
+        self.declare(
+            node.ident.to_id(),
+            param_ids(node.function.params.iter().map(|param| &param.pat)),
+        );
+
+        node.visit_children_with(self);
+    }
+
+    fn visit_var_declarator(&mut self, node: &VarDeclarator) {
+        if let Some(init) = &node.init {
+            if let Some(name) = node.name.as_ident() {
+                let params = match &**init {
+                    Expr::Arrow(arrow) => Some(param_ids(arrow.params.iter())),
+                    Expr::Fn(fn_expr) => Some(param_ids(
+                        fn_expr.function.params.iter().map(|param| &param.pat),
+                    )),
+                    _ => None,
+                };
+
+                if let Some(params) = params {
+                    self.declare(name.to_id(), params);
+                }
+            }
+
+            // `const {useExtracted} = require('next-intl')`
+            if let Pat::Object(pat) = &node.name {
+                let init = match unwrap_expr(init) {
+                    Expr::Await(AwaitExpr { arg, .. }) => unwrap_expr(arg),
+                    init => init,
+                };
+                if let Some(source) = init.as_call().and_then(module_source) {
+                    for prop in &pat.props {
+                        let (key, value) = match prop {
+                            ObjectPatProp::KeyValue(KeyValuePatProp {
+                                key: PropName::Ident(key),
+                                value: box Pat::Ident(value),
+                            }) => (&key.sym, value),
+                            ObjectPatProp::Assign(AssignPatProp { key, .. }) => (&key.sym, key),
+                            _ => continue,
+                        };
+                        if (self.is_hook)(&source, key) {
+                            self.candidates.insert(value.to_id());
+                        }
+                    }
+                }
+            }
+
+            // `const t = useExtracted()` or `const {rich} = t`
+            if self.references_candidate(init) {
+                self.add_candidates(&node.name);
+            }
+        }
+
+        node.visit_children_with(self);
+    }
+
+    fn visit_assign_expr(&mut self, node: &AssignExpr) {
+        if self.references_candidate(&node.right) {
+            match &node.left {
+                AssignTarget::Simple(SimpleAssignTarget::Ident(name)) => {
+                    self.candidates.insert(name.to_id());
+                }
+                AssignTarget::Simple(SimpleAssignTarget::Member(MemberExpr {
+                    obj: box Expr::This(_),
+                    prop: MemberProp::Ident(prop),
+                    ..
+                })) => {
+                    self.this_candidates.insert(prop.sym.clone());
+                }
+                _ => {}
+            }
+        }
+
+        node.visit_children_with(self);
+    }
+
+    fn visit_call_expr(&mut self, node: &CallExpr) {
+        let Callee::Expr(callee) = &node.callee else {
+            node.visit_children_with(self);
+            return;
+        };
+
+        // `getExtracted().then((t) => ...)`
+        if self.references_candidate(callee) {
+            for arg in &node.args {
+                match unwrap_expr(&arg.expr) {
+                    Expr::Arrow(arrow) => {
+                        for param in &arrow.params {
+                            self.add_candidates(param);
+                        }
+                    }
+                    Expr::Fn(FnExpr { function, .. }) => {
+                        for param in &function.params {
+                            self.add_candidates(&param.pat);
+                        }
+                    }
+                    _ => {}
+                }
+            }
+        }
+
+        // `renderRow(t, item)`
+        if let Expr::Ident(callee) = &**callee {
+            let callee = callee.to_id();
+            let mut params = Vec::new();
+            if let Some(&index) = self.declared.get(&callee) {
+                for (arg, param) in node.args.iter().zip(&self.declarations[index].1) {
+                    if let (None, Expr::Ident(arg), Some(param)) = (arg.spread, &*arg.expr, param) {
+                        if self.candidates.contains(&arg.to_id()) {
+                            params.push(param.clone());
+                        }
+                    }
+                }
+            }
+            if !params.is_empty() {
+                self.receiving.insert(callee);
+                self.candidates.extend(params);
+            }
+        }
+
+        node.visit_children_with(self);
+    }
+}
+
+/// Looks for references to candidates, without descending into functions
+/// since these are only called later.
+struct CandidateFinder<'a> {
+    candidates: &'a FxHashSet<Id>,
+    this_candidates: &'a FxHashSet<Atom>,
+    found: bool,
+}
+
+impl Visit for CandidateFinder<'_> {
+    fn visit_member_expr(&mut self, node: &MemberExpr) {
+        if let (Expr::This(_), MemberProp::Ident(prop)) = (&*node.obj, &node.prop) {
+            if self.this_candidates.contains(&prop.sym) {
+                self.found = true;
+            }
+        }
+
+        node.visit_children_with(self);
+    }
+
+    fn visit_ident(&mut self, node: &Ident) {
+        if self.candidates.contains(&node.to_id()) {
+            self.found = true;
+        }
+    }
+
+    fn visit_arrow_expr(&mut self, _: &ArrowExpr) {}
+
+    fn visit_function(&mut self, _: &Function) {}
+}
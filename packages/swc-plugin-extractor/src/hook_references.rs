@@ -0,0 +1,144 @@
+use rustc_hash::FxHashMap;
+use swc_atoms::Atom;
+use swc_ecma_ast::*;
+use swc_ecma_visit::{Visit, VisitWith};
+
+use crate::{emit_error, message_descriptors::MessageMarker, static_eval::unwrap_expr};
+
+const USED_AS_VALUE: &str = "used as a value";
+
+/// Reports references to extracted hooks that remain after the transform,
+/// e.g. `export {useExtracted}` or `[useExtracted].map(...)`. These would
+/// throw at runtime in production, since only calls can be compiled. The
+/// same applies to `msg` and `defineMessages`, whose imports are removed.
+pub fn check_hook_references<N>(
+    node: &N,
+    hook_local_names: &FxHashMap<Id, Atom>,
+    message_markers: &FxHashMap<Id, MessageMarker>,
+) where
+    N: for<'a> VisitWith<HookReferenceChecker<'a>>,
+{
+    node.visit_with(&mut HookReferenceChecker {
+        hook_local_names,
+        message_markers,
+        usage: USED_AS_VALUE,
+    });
+}
+
+pub struct HookReferenceChecker<'a> {
+    hook_local_names: &'a FxHashMap<Id, Atom>,
+    message_markers: &'a FxHashMap<Id, MessageMarker>,
+
+    /// How the expression that is currently visited is used
+    usage: &'static str,
+}
+
+impl HookReferenceChecker<'_> {
+    fn visit_with_usage<N: VisitWith<Self>>(&mut self, node: &N, usage: &'static str) {
+        let prev = std::mem::replace(&mut self.usage, usage);
+        node.visit_with(self);
+        self.usage = prev;
+    }
+}
+
+impl Visit for HookReferenceChecker<'_> {
+    fn visit_ident(&mut self, ident: &Ident) {
+        if let Some(marker) = self.message_markers.get(&ident.to_id()) {
+            emit_error(
+                ident.span,
+                &format!(
+                    "`{}` is {} here, but it can only be called directly (e.g. `{}`). This \
+                     reference can't be compiled and would throw at runtime.",
+                    ident.sym,
+                    self.usage,
+                    match marker {
+                        MessageMarker::Msg => format!("{}('Hello!')", ident.sym),
+                        MessageMarker::DefineMessages =>
+                            format!("{}({{greeting: 'Hello!'}})", ident.sym),
+                    }
+                ),
+            );
+            return;
+        }
+
+        if !self.hook_local_names.contains_key(&ident.to_id()) {
+            return;
+        }
+
+        emit_error(
+            ident.span,
+            &format!(
+                "`{}` is {} here, but extracted hooks can only be called directly \
+                 (e.g. `const t = {}()`). This reference can't be compiled and would throw \
+                 at runtime.",
+                ident.sym, self.usage, ident.sym
+            ),
+        );
+    }
+
+    fn visit_expr(&mut self, expr: &Expr) {
+        match unwrap_expr(expr) {
+            Expr::Ident(ident) => self.visit_ident(ident),
+            expr => {
+                let prev = std::mem::replace(&mut self.usage, USED_AS_VALUE);
+                expr.visit_children_with(self);
+                self.usage = prev;
+            }
+        }
+    }
+
+    fn visit_call_expr(&mut self, call: &CallExpr) {
+        // Calls of `msg` that couldn't be compiled are already reported
+        let is_marker_call = matches!(
+            &call.callee,
+            Callee::Expr(box Expr::Ident(callee)) if self.message_markers.contains_key(&callee.to_id())
+        );
+        if !is_marker_call {
+            call.callee.visit_with(self);
+        }
+        for arg in &call.args {
+            let usage = if arg.spread.is_some() {
+                "spread into a call"
+            } else {
+                "passed as an argument"
+            };
+            self.visit_with_usage(&*arg.expr, usage);
+        }
+    }
+
+    fn visit_new_expr(&mut self, new: &NewExpr) {
+        new.callee.visit_with(self);
+        for arg in new.args.iter().flatten() {
+            self.visit_with_usage(&*arg.expr, "passed as an argument");
+        }
+    }
+
+    fn visit_array_lit(&mut self, array: &ArrayLit) {
+        for elem in array.elems.iter().flatten() {
+            let usage = if elem.spread.is_some() {
+                "spread into an array"
+            } else {
+                "stored in an array"
+            };
+            self.visit_with_usage(&*elem.expr, usage);
+        }
+    }
+
+    fn visit_object_lit(&mut self, object: &ObjectLit) {
+        self.visit_with_usage(&object.props, "stored in an object");
+    }
+
+    fn visit_export_default_expr(&mut self, export: &ExportDefaultExpr) {
+        self.visit_with_usage(&export.expr, "re-exported");
+    }
+
+    fn visit_named_export(&mut self, export: &NamedExport) {
+        // Re-exports from another module don't refer to local bindings
+        if export.src.is_none() {
+            self.visit_with_usage(&export.specifiers, "re-exported");
+        }
+    }
+
+    // References in types are erased
+    fn visit_ts_type(&mut self, _: &TsType) {}
+}
@@ -0,0 +1,488 @@
+use rustc_hash::FxHashSet;
+
+/// A syntax error in an ICU message, located by byte offsets into its text.
+#[derive(Debug)]
+pub struct IcuError {
+    pub message: String,
+    pub start: usize,
+    pub end: usize,
+}
+
+/// An argument or tag of a message that needs a value, located by the byte
+/// offsets of its name.
+#[derive(Debug)]
+pub struct Argument<'a> {
+    pub name: &'a str,
+    pub kind: ArgumentKind,
+    pub start: usize,
+    pub end: usize,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum ArgumentKind {
+    /// `{name}`
+    Simple,
+    Number,
+    Date,
+    Time,
+    Plural,
+    SelectOrdinal,
+    Select,
+    /// `<b>...</b>`
+    Tag,
+}
+
+/// Parses an ICU message like `You have {count, plural, one {# item} other
+/// {# items}}` with the syntax that `intl-messageformat` supports at runtime,
+/// including rich text tags like `<b>...</b>`.
+pub fn parse_message(text: &str) -> Result<Vec<Argument<'_>>, IcuError> {
+    let mut parser = Parser {
+        text,
+        pos: 0,
+        arguments: Vec::new(),
+    };
+    parser.parse_elements(0, false, None)?;
+    Ok(parser.arguments)
+}
+
+#[derive(Clone, Copy, PartialEq, Eq)]
+enum OptionsKind {
+    Plural,
+    Select,
+}
+
+/// A tag whose children are being parsed, e.g. `<b>` at its offset.
+struct OpenTag<'a> {
+    name: &'a str,
+    start: usize,
+}
+
+struct Parser<'a> {
+    text: &'a str,
+    pos: usize,
+    arguments: Vec<Argument<'a>>,
+}
+
+impl<'a> Parser<'a> {
+    fn peek(&self) -> Option<char> {
+        self.text[self.pos..].chars().next()
+    }
+
+    fn peek_second(&self) -> Option<char> {
+        self.text[self.pos..].chars().nth(1)
+    }
+
+    fn bump(&mut self) {
+        if let Some(char) = self.peek() {
+            self.pos += char.len_utf8();
+        }
+    }
+
+    fn skip_whitespace(&mut self) {
+        while self.peek().is_some_and(char::is_whitespace) {
+            self.bump();
+        }
+    }
+
+    fn take_while(&mut self, predicate: impl Fn(char) -> bool) -> &'a str {
+        let start = self.pos;
+        while self.peek().is_some_and(&predicate) {
+            self.bump();
+        }
+        &self.text[start..self.pos]
+    }
+
+    /// An error at the current character, or at the end of the text.
+    fn error_here(&self, message: impl Into<String>) -> IcuError {
+        let end = self.pos + self.peek().map_or(0, char::len_utf8);
+        self.error(self.pos, end, message)
+    }
+
+    /// An error at the `{` of an argument that is missing its `}`.
+    fn unclosed_argument(&self, start: usize) -> IcuError {
+        self.error(
+            start,
+            start + 1,
+            "The argument is never closed, expected `}`.",
+        )
+    }
+
+    fn error(&self, start: usize, end: usize, message: impl Into<String>) -> IcuError {
+        IcuError {
+            message: message.into(),
+            start,
+            end,
+        }
+    }
+
+    /// Parses text, arguments and tags until the `}` that closes an option,
+    /// the closing tag of `tag` or the end of the text.
+    fn parse_elements(
+        &mut self,
+        depth: usize,
+        in_plural: bool,
+        tag: Option<OpenTag<'a>>,
+    ) -> Result<(), IcuError> {
+        loop {
+            match self.peek() {
+                None => break,
+                Some('{') => self.parse_argument(depth)?,
+                Some('}') if depth > 0 => break,
+                Some('<') if self.peek_second() == Some('/') => {
+                    return self.parse_closing_tag(tag);
+                }
+                Some('<')
+                    if self
+                        .peek_second()
+                        .is_some_and(|char| char.is_ascii_alphabetic()) =>
+                {
+                    self.parse_tag(depth, in_plural)?;
+                }
+                Some('\'') => self.parse_quote(in_plural),
+                Some(_) => self.bump(),
+            }
+        }
+
+        match tag {
+            Some(tag) => Err(self.error(
+                tag.start,
+                tag.start + tag.name.len() + 2,
+                format!("The tag `<{}>` is never closed.", tag.name),
+            )),
+            None => Ok(()),
+        }
+    }
+
+    /// Apostrophes escape syntax characters, e.g. `'{'` or `'<b>'`, while
+    /// `''` is a literal apostrophe. Other apostrophes are literal text.
+    fn parse_quote(&mut self, in_plural: bool) {
+        self.bump();
+        match self.peek() {
+            Some('\'') => self.bump(),
+            Some('{' | '}' | '<' | '>') => self.skip_quoted(),
+            Some('#') if in_plural => self.skip_quoted(),
+            _ => {}
+        }
+    }
+
+    /// Quoted text continues until the next apostrophe that isn't doubled,
+    /// or until the end of the text.
+    fn skip_quoted(&mut self) {
+        while let Some(char) = self.peek() {
+            self.bump();
+            if char == '\'' {
+                if self.peek() == Some('\'') {
+                    self.bump();
+                } else {
+                    break;
+                }
+            }
+        }
+    }
+
+    fn parse_argument(&mut self, depth: usize) -> Result<(), IcuError> {
+        let start = self.pos;
+        self.bump();
+        self.skip_whitespace();
+
+        if self.peek() == Some('}') {
+            return Err(self.error(start, self.pos + 1, "Arguments need a name, e.g. `{name}`."));
+        }
+        let name_start = self.pos;
+        let name = self.take_while(is_identifier_char);
+        if name.is_empty() {
+            return Err(match self.peek() {
+                None => self.unclosed_argument(start),
+                Some(_) => self.error_here("Arguments need a name, e.g. `{name}`."),
+            });
+        }
+        self.skip_whitespace();
+
+        let kind = match self.peek() {
+            None => return Err(self.unclosed_argument(start)),
+            Some('}') => {
+                self.bump();
+                ArgumentKind::Simple
+            }
+            Some(',') => {
+                self.bump();
+                self.skip_whitespace();
+                let type_start = self.pos;
+                let argument_type = self.take_while(|char| char.is_ascii_alphabetic());
+                let kind = match argument_type {
+                    "" => {
+                        return Err(match self.peek() {
+                            None => self.unclosed_argument(start),
+                            Some(_) => self.error_here(format!(
+                                "Expected a type for the argument `{name}`, e.g. \
+                                 `{{{name}, number}}`."
+                            )),
+                        })
+                    }
+                    "number" => ArgumentKind::Number,
+                    "date" => ArgumentKind::Date,
+                    "time" => ArgumentKind::Time,
+                    "plural" => ArgumentKind::Plural,
+                    "selectordinal" => ArgumentKind::SelectOrdinal,
+                    "select" => ArgumentKind::Select,
+                    _ => {
+                        return Err(self.error(
+                            type_start,
+                            self.pos,
+                            format!(
+                                "`{argument_type}` isn't a valid argument type, expected \
+                                 `number`, `date`, `time`, `plural`, `selectordinal` or \
+                                 `select`."
+                            ),
+                        ))
+                    }
+                };
+                match kind {
+                    ArgumentKind::Plural | ArgumentKind::SelectOrdinal => {
+                        self.parse_options(start, name, OptionsKind::Plural, depth)?
+                    }
+                    ArgumentKind::Select => {
+                        self.parse_options(start, name, OptionsKind::Select, depth)?
+                    }
+                    _ => self.parse_style(start, name)?,
+                }
+                kind
+            }
+            Some(_) => {
+                return Err(self.error_here(format!(
+                    "Expected `}}` or `,` after the argument `{name}`. Names can't contain \
+                     spaces or special characters."
+                )))
+            }
+        };
+
+        self.arguments.push(Argument {
+            name,
+            kind,
+            start: name_start,
+            end: name_start + name.len(),
+        });
+        Ok(())
+    }
+
+    /// Parses an optional style like `{price, number, ::currency/EUR}`.
+    fn parse_style(&mut self, start: usize, name: &str) -> Result<(), IcuError> {
+        self.skip_whitespace();
+        match self.peek() {
+            Some('}') => {
+                self.bump();
+                return Ok(());
+            }
+            Some(',') => self.bump(),
+            None => return Err(self.unclosed_argument(start)),
+            Some(_) => {
+                return Err(self.error_here(format!(
+                    "Expected `}}` or `,` after the type of the argument `{name}`."
+                )))
+            }
+        }
+
+        // Styles can contain quoted and nested braces
+        let style_start = self.pos;
+        let mut nesting = 0;
+        loop {
+            match self.peek() {
+                None => return Err(self.unclosed_argument(start)),
+                Some('\'') => {
+                    self.bump();
+                    self.skip_quoted();
+                }
+                Some('{') => {
+                    nesting += 1;
+                    self.bump();
+                }
+                Some('}') if nesting > 0 => {
+                    nesting -= 1;
+                    self.bump();
+                }
+                Some('}') => break,
+                Some(_) => self.bump(),
+            }
+        }
+
+        if self.text[style_start..self.pos].trim().is_empty() {
+            return Err(self.error_here(format!(
+                "Expected a style for the argument `{name}` after `,`."
+            )));
+        }
+        self.bump();
+        Ok(())
+    }
+
+    /// Parses the options of `plural`, `selectordinal` and `select`, e.g.
+    /// `one {# item} other {# items}`.
+    fn parse_options(
+        &mut self,
+        start: usize,
+        name: &str,
+        kind: OptionsKind,
+        depth: usize,
+    ) -> Result<(), IcuError> {
+        self.skip_whitespace();
+        if self.peek() != Some(',') {
+            return Err(self.error_here(format!(
+                "Expected `,` followed by the options of the argument `{name}`."
+            )));
+        }
+        self.bump();
+
+        let mut selectors = FxHashSet::default();
+        let mut is_first = true;
+        loop {
+            self.skip_whitespace();
+            let selector_start = self.pos;
+            match self.peek() {
+                None => return Err(self.unclosed_argument(start)),
+                Some('}') => break,
+                Some('=') if kind == OptionsKind::Plural => {
+                    self.bump();
+                    if self.take_while(|char| char.is_ascii_digit()).is_empty() {
+                        return Err(self.error(
+                            selector_start,
+                            self.pos,
+                            "Exact plural selectors need a number, e.g. `=0`.",
+                        ));
+                    }
+                }
+                Some(_) => {
+                    let selector = self.take_while(is_identifier_char);
+                    if selector.is_empty() {
+                        return Err(self
+                            .error_here(format!("Expected an option for the argument `{name}`.")));
+                    }
+
+                    // {count, plural, offset:1 ...}
+                    if kind == OptionsKind::Plural
+                        && is_first
+                        && selector == "offset"
+                        && self.peek() == Some(':')
+                    {
+                        self.bump();
+                        self.skip_whitespace();
+                        if self.peek() == Some('-') {
+                            self.bump();
+                        }
+                        if self.take_while(|char| char.is_ascii_digit()).is_empty() {
+                            return Err(self.error_here("Expected a number after `offset:`."));
+                        }
+                        is_first = false;
+                        continue;
+                    }
+                }
+            }
+            is_first = false;
+
+            let selector = &self.text[selector_start..self.pos];
+            if !selectors.insert(selector) {
+                return Err(self.error(
+                    selector_start,
+                    self.pos,
+                    format!("The option `{selector}` is defined more than once."),
+                ));
+            }
+
+            self.skip_whitespace();
+            if self.peek() != Some('{') {
+                return Err(self.error_here(format!(
+                    "Expected `{{` to start the message of the option `{selector}`."
+                )));
+            }
+            let message_start = self.pos;
+            self.bump();
+            self.parse_elements(depth + 1, kind == OptionsKind::Plural, None)?;
+            if self.peek() != Some('}') {
+                return Err(self.error(
+                    message_start,
+                    message_start + 1,
+                    format!(
+                        "The message of the option `{selector}` is never closed, expected `}}`."
+                    ),
+                ));
+            }
+            self.bump();
+        }
+
+        if !selectors.contains("other") {
+            return Err(self.error(
+                start,
+                self.pos + 1,
+                format!("The argument `{name}` needs an `other` option."),
+            ));
+        }
+        self.bump();
+        Ok(())
+    }
+
+    /// Parses a tag like `<b>...</b>`. Self-closing tags like `<br/>` are
+    /// text, since tags can only be rendered with their children.
+    fn parse_tag(&mut self, depth: usize, in_plural: bool) -> Result<(), IcuError> {
+        let start = self.pos;
+        self.bump();
+        let name = self.take_while(is_tag_name_char);
+
+        if self.text[self.pos..].starts_with("/>") {
+            self.pos += 2;
+            return Ok(());
+        }
+        if self.peek() != Some('>') {
+            return Err(self.error(
+                start,
+                self.pos + self.peek().map_or(0, char::len_utf8),
+                format!("The tag `<{name}` is invalid, tags can't have attributes or spaces."),
+            ));
+        }
+        self.bump();
+
+        self.parse_elements(depth, in_plural, Some(OpenTag { name, start }))?;
+        self.arguments.push(Argument {
+            name,
+            kind: ArgumentKind::Tag,
+            start: start + 1,
+            end: start + 1 + name.len(),
+        });
+        Ok(())
+    }
+
+    fn parse_closing_tag(&mut self, tag: Option<OpenTag>) -> Result<(), IcuError> {
+        let start = self.pos;
+        self.pos += 2;
+        let name = self.take_while(is_tag_name_char);
+        if self.peek() != Some('>') {
+            return Err(self.error(
+                start,
+                self.pos + self.peek().map_or(0, char::len_utf8),
+                format!("The closing tag `</{name}` is invalid, expected `>`."),
+            ));
+        }
+        self.bump();
+
+        match tag {
+            Some(tag) if tag.name == name => Ok(()),
+            Some(tag) => Err(self.error(
+                start,
+                self.pos,
+                format!("`</{name}>` doesn't match the open tag `<{}>`.", tag.name),
+            )),
+            None => Err(self.error(
+                start,
+                self.pos,
+                format!("`</{name}>` doesn't have an opening tag."),
+            )),
+        }
+    }
+}
+
+/// Characters of argument names and selectors, i.e. everything except
+/// whitespace and syntax characters.
+fn is_identifier_char(char: char) -> bool {
+    !char.is_whitespace() && !matches!(char, '{' | '}' | '<' | '>' | '#' | ',' | '\'' | ':' | '=')
+}
+
+fn is_tag_name_char(char: char) -> bool {
+    char.is_alphanumeric() || matches!(char, '-' | '_' | '.')
+}
@@ -0,0 +1,251 @@
+use rustc_hash::FxHashSet;
+use swc_atoms::Atom;
+use swc_common::{Spanned, DUMMY_SP};
+use swc_ecma_ast::*;
+
+use crate::{emit_error, static_eval::unwrap_expr};
+
+/// The ICU message of the children of an `<Extracted>` element along with
+/// the values it is rendered with, e.g. `Hello <b>{name}</b>` with
+/// `{name, b: (chunks) => <b>{chunks}</b>}`.
+pub struct JsxMessage {
+    pub message: String,
+    pub values: Vec<PropOrSpread>,
+}
+
+impl JsxMessage {
+    /// Returns `None` if a child can't be represented in a message, in which
+    /// case an error has been emitted.
+    pub fn from_children(children: &[JSXElementChild]) -> Option<Self> {
+        let mut builder = MessageBuilder {
+            message: String::new(),
+            values: Vec::new(),
+            names: Default::default(),
+            arguments: Default::default(),
+            quoted_end: None,
+            is_valid: true,
+        };
+        builder.push_children(children);
+
+        builder.is_valid.then(|| JsxMessage {
+            message: builder.message.trim().to_string(),
+            values: builder.values,
+        })
+    }
+}
+
+struct MessageBuilder {
+    message: String,
+    values: Vec<PropOrSpread>,
+
+    /// Names of the arguments and tags in the message
+    names: FxHashSet<Atom>,
+
+    /// Variables that are passed as an argument of the same name
+    arguments: FxHashSet<Atom>,
+
+    /// The end of the last quoted run of syntax characters, e.g. `'{'`,
+    /// which following ones are added to
+    quoted_end: Option<usize>,
+
+    is_valid: bool,
+}
+
+impl MessageBuilder {
+    fn push_children(&mut self, children: &[JSXElementChild]) {
+        for child in children {
+            match child {
+                JSXElementChild::JSXText(text) => self.push_text(&jsx_text_value(&text.value)),
+
+                JSXElementChild::JSXExprContainer(JSXExprContainer {
+                    expr: JSXExpr::Expr(expr),
+                    ..
+                }) => self.push_expr(expr),
+
+                // Comments: {/* ... */}
+                JSXElementChild::JSXExprContainer(JSXExprContainer {
+                    expr: JSXExpr::JSXEmptyExpr(_),
+                    ..
+                }) => {}
+
+                JSXElementChild::JSXElement(element) => self.push_element(element),
+                JSXElementChild::JSXFragment(fragment) => self.push_children(&fragment.children),
+
+                JSXElementChild::JSXSpreadChild(spread) => {
+                    emit_error(
+                        spread.span,
+                        "Spread children can't be extracted, messages need to be statically \
+                         analyzable.",
+                    );
+                    self.is_valid = false;
+                }
+            }
+        }
+    }
+
+    /// Escapes ICU syntax in literal text. Apostrophes are doubled and syntax
+    /// characters are quoted, where adjacent ones share a quoted run, since
+    /// `'{''{'` would be read as `{'{`.
+    fn push_text(&mut self, text: &str) {
+        for char in text.chars() {
+            let is_syntax = matches!(char, '{' | '}' | '<');
+            let is_quoted = self.quoted_end == Some(self.message.len());
+
+            if is_quoted && (is_syntax || char == '\'') {
+                // Reopen the previous run
+                self.message.pop();
+            } else if is_syntax {
+                self.message.push('\'');
+            }
+
+            if char == '\'' {
+                self.message.push_str("''");
+            } else {
+                self.message.push(char);
+            }
+
+            if is_syntax || (is_quoted && char == '\'') {
+                self.message.push('\'');
+                self.quoted_end = Some(self.message.len());
+            }
+        }
+    }
+
+    fn push_expr(&mut self, expr: &Expr) {
+        match unwrap_expr(expr) {
+            Expr::Lit(Lit::Str(s)) => self.push_text(&s.value.to_string_lossy()),
+
+            // {name}
+            Expr::Ident(ident) => {
+                let name = if self.arguments.contains(&ident.sym) {
+                    ident.sym.clone()
+                } else if self.names.contains(&ident.sym) {
+                    // Conflicts with the name of a tag
+                    let name = self.unique_name(&ident.sym);
+                    self.push_value(name.clone(), ident.clone().into());
+                    name
+                } else {
+                    self.names.insert(ident.sym.clone());
+                    self.arguments.insert(ident.sym.clone());
+                    self.values
+                        .push(PropOrSpread::Prop(Box::new(Prop::Shorthand(ident.clone()))));
+                    ident.sym.clone()
+                };
+
+                self.message.push('{');
+                self.message.push_str(&name);
+                self.message.push('}');
+            }
+
+            expr => {
+                emit_error(
+                    expr.span(),
+                    "Only identifiers and strings can be used within `<Extracted>`, e.g. \
+                     `{name}`. Assign the value to a variable first.",
+                );
+                self.is_valid = false;
+            }
+        }
+    }
+
+    fn push_element(&mut self, element: &JSXElement) {
+        let JSXElementName::Ident(tag) = &element.opening.name else {
+            emit_error(
+                element.opening.name.span(),
+                "Only elements with a plain name like `<b>` or `<Link>` can be used within \
+                 `<Extracted>`.",
+            );
+            self.is_valid = false;
+            return;
+        };
+        let name = self.unique_name(&tag.sym);
+
+        // Self-closing elements are rendered as an argument: {br}
+        if element.closing.is_none() {
+            self.message.push('{');
+            self.message.push_str(&name);
+            self.message.push('}');
+            self.push_value(name, Expr::JSXElement(Box::new(element.clone())));
+            return;
+        }
+
+        self.message.push('<');
+        self.message.push_str(&name);
+        self.message.push('>');
+        self.push_children(&element.children);
+        self.message.push_str("</");
+        self.message.push_str(&name);
+        self.message.push('>');
+
+        // (chunks) => <b>{chunks}</b>
+        let chunks = Ident::new_no_ctxt("chunks".into(), DUMMY_SP);
+        let mut chunk_element = element.clone();
+        chunk_element.children = vec![JSXElementChild::JSXExprContainer(JSXExprContainer {
+            span: DUMMY_SP,
+            expr: JSXExpr::Expr(Box::new(chunks.clone().into())),
+        })];
+        self.push_value(
+            name,
+            Expr::Arrow(ArrowExpr {
+                params: vec![chunks.into()],
+                body: Box::new(BlockStmtOrExpr::Expr(Box::new(Expr::JSXElement(Box::new(
+                    chunk_element,
+                ))))),
+                ..Default::default()
+            }),
+        );
+    }
+
+    fn push_value(&mut self, name: Atom, value: Expr) {
+        self.values
+            .push(PropOrSpread::Prop(Box::new(Prop::KeyValue(KeyValueProp {
+                key: PropName::Ident(IdentName::new(name, DUMMY_SP)),
+                value: Box::new(value),
+            }))));
+    }
+
+    /// Tags can occur multiple times with different attributes, therefore
+    /// repeated names are numbered, e.g. `<a>` and `<a2>`.
+    fn unique_name(&mut self, name: &Atom) -> Atom {
+        let mut unique: Atom = name.clone();
+        let mut index = 1;
+        while self.names.contains(&unique) {
+            index += 1;
+            unique = format!("{name}{index}").into();
+        }
+        self.names.insert(unique.clone());
+        unique
+    }
+}
+
+/// Collapses the whitespace of JSX text like React does: lines are trimmed
+/// and joined with a space, while lines with only whitespace are removed.
+fn jsx_text_value(value: &str) -> String {
+    let lines = value
+        .split('\n')
+        .map(|line| line.strip_suffix('\r').unwrap_or(line))
+        .collect::<Vec<_>>();
+    let last_non_empty = lines
+        .iter()
+        .rposition(|line| line.chars().any(|char| char != ' ' && char != '\t'));
+
+    let mut text = String::new();
+    for (index, line) in lines.iter().enumerate() {
+        let mut line = line.replace('\t', " ");
+        if index != 0 {
+            line = line.trim_start_matches(' ').to_string();
+        }
+        if index != lines.len() - 1 {
+            line = line.trim_end_matches(' ').to_string();
+        }
+        if line.is_empty() {
+            continue;
+        }
+
+        text.push_str(&line);
+        if Some(index) != last_non_empty {
+            text.push(' ');
+        }
+    }
+    text
+}
@@ -1,17 +1,175 @@
 use base64::Engine;
-use sha2::{Digest, Sha512};
+use serde::Deserialize;
+use serde_json::Value;
+use sha2::{Digest, Sha256, Sha512};
 use swc_atoms::Wtf8Atom;
 
-pub struct KeyGenerator;
+use crate::{explicit_ids::invalid_key_char, NAMESPACE_SEPARATOR};
+
+/// Separates the context from the message like in compiled gettext catalogs
+const CONTEXT_SEPARATOR: u8 = 0x04;
+
+/// Words of the message that are included in a slug
+const MAX_SLUG_WORDS: usize = 4;
+
+/// How keys are generated from messages.
+#[derive(Debug, Clone, Deserialize)]
+#[serde(rename_all = "camelCase", default)]
+pub struct KeyConfig {
+    pub algorithm: HashAlgorithm,
+
+    /// The number of characters of the hash that are used
+    pub length: usize,
+
+    /// Prepended to every generated key (e.g. `app_`)
+    pub prefix: Option<String>,
+
+    pub mode: KeyMode,
+
+    /// The keys of messages of other files, which are used to detect
+    /// collisions (see `KeyRegistry`)
+    pub registry: Option<Value>,
+
+    /// Resolves collisions by lengthening the key of the newer message
+    /// instead of reporting an error
+    pub lengthen_collisions: bool,
+
+    /// Includes the description of a message in its hash, so that the same
+    /// text with different descriptions results in different keys
+    pub hash_description: bool,
+}
+
+impl Default for KeyConfig {
+    fn default() -> Self {
+        Self {
+            algorithm: HashAlgorithm::Sha512,
+            length: 6,
+            prefix: None,
+            mode: KeyMode::Hash,
+            registry: None,
+            lengthen_collisions: false,
+            hash_description: false,
+        }
+    }
+}
+
+#[derive(Debug, Clone, Copy, Deserialize)]
+#[serde(rename_all = "lowercase")]
+pub enum HashAlgorithm {
+    Sha256,
+    Sha512,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Deserialize)]
+#[serde(rename_all = "lowercase")]
+pub enum KeyMode {
+    /// URL-safe base64 of the hash (e.g. `OpKKos`)
+    Hash,
+
+    /// Readable words of the message with a hex hash (e.g. `checkout_total_a1b2`)
+    Slug,
+}
+
+pub struct KeyGenerator {
+    config: KeyConfig,
+}
 
 impl KeyGenerator {
-    pub fn generate(message: &Wtf8Atom) -> String {
-        let hash = Sha512::digest(message.as_bytes());
-        // URL_SAFE_NO_PAD uses `-_` instead of `+/`. The standard base64
-        // alphabet can produce keys starting with `/`, which Googlebot
-        // interprets as relative URL paths when they appear in serialised
-        // page data and reports as 404s in Search Console (see #2250).
-        let base64 = base64::engine::general_purpose::URL_SAFE_NO_PAD.encode(hash);
-        base64[..6].to_string()
+    /// A `prefix` that can't be used in keys is reported to `errors` and
+    /// ignored.
+    pub fn new(mut config: KeyConfig, errors: &mut Vec<String>) -> Self {
+        if let Some(prefix) = &config.prefix {
+            if let Err(error) = validate_prefix(prefix) {
+                errors.push(error);
+                config.prefix = None;
+            }
+        }
+        Self { config }
+    }
+
+    /// The context of a message, e.g. `file` for `Open`, is included in the
+    /// hash like a `msgctxt` in gettext.
+    pub fn generate(&self, message: &Wtf8Atom, context: Option<&Wtf8Atom>) -> String {
+        self.generate_with_length(message, context, self.config.length)
+    }
+
+    /// Generates a key with a hash of the given length, which is limited by
+    /// the length of the encoded hash.
+    pub fn generate_with_length(
+        &self,
+        message: &Wtf8Atom,
+        context: Option<&Wtf8Atom>,
+        length: usize,
+    ) -> String {
+        let mut input = Vec::new();
+        if let Some(context) = context {
+            input.extend_from_slice(context.as_bytes());
+            input.push(CONTEXT_SEPARATOR);
+        }
+        input.extend_from_slice(message.as_bytes());
+
+        let hash = match self.config.algorithm {
+            HashAlgorithm::Sha256 => Sha256::digest(&input).to_vec(),
+            HashAlgorithm::Sha512 => Sha512::digest(&input).to_vec(),
+        };
+
+        let mut key = self.config.prefix.clone().unwrap_or_default();
+        match self.config.mode {
+            KeyMode::Hash => {
+                // URL_SAFE_NO_PAD uses `-_` instead of `+/`. The standard base64
+                // alphabet can produce keys starting with `/`, which Googlebot
+                // interprets as relative URL paths when they appear in serialised
+                // page data and reports as 404s in Search Console (see #2250).
+                let base64 = base64::engine::general_purpose::URL_SAFE_NO_PAD.encode(hash);
+                key.push_str(truncate(&base64, length));
+            }
+            KeyMode::Slug => {
+                let hex = hash
+                    .iter()
+                    .map(|byte| format!("{byte:02x}"))
+                    .collect::<String>();
+                let slug = slug(&message.to_string_lossy());
+                if !slug.is_empty() {
+                    key.push_str(&slug);
+                    key.push('_');
+                }
+                key.push_str(truncate(&hex, length));
+            }
+        }
+        key
+    }
+}
+
+fn validate_prefix(prefix: &str) -> Result<(), String> {
+    if prefix.contains(NAMESPACE_SEPARATOR) {
+        return Err(format!(
+            "The key prefix `{prefix}` from `keys.prefix` can't contain \
+             `{NAMESPACE_SEPARATOR}`, as it's used to separate namespaces."
+        ));
     }
+
+    if let Some(char) = invalid_key_char(prefix) {
+        return Err(format!(
+            "The key prefix `{prefix}` from `keys.prefix` contains `{char}`, but keys can only \
+             consist of letters, digits, `_` and `-`."
+        ));
+    }
+
+    Ok(())
+}
+
+fn truncate(value: &str, length: usize) -> &str {
+    &value[..length.clamp(1, value.len())]
+}
+
+/// Lowercase words of a message, e.g. `checkout_total` for `Checkout total`
+/// or `qué_tal` for `¿Qué tal?`.
+fn slug(message: &str) -> String {
+    message
+        .split(|char: char| !char.is_alphanumeric())
+        .filter(|word| !word.is_empty())
+        .take(MAX_SLUG_WORDS)
+        .map(|word| word.to_lowercase())
+        .collect::<Vec<_>>()
+        .join("_")
 }
@@ -0,0 +1,102 @@
+use rustc_hash::FxHashMap;
+use serde::Deserialize;
+use serde_json::Value;
+
+use crate::NAMESPACE_SEPARATOR;
+
+/// Keys of the messages of all files from a previous extraction, which are
+/// used to detect collisions across files. The registry is passed along with
+/// the config like `{"OpKKos": {"message": "Hello!", "path": "src/Greeting.tsx"}}`.
+#[derive(Default, Deserialize)]
+pub struct KeyRegistry(FxHashMap<String, RegisteredKey>);
+
+#[derive(Deserialize)]
+pub struct RegisteredKey {
+    pub message: String,
+    #[serde(default)]
+    pub context: Option<String>,
+    pub path: String,
+}
+
+impl KeyRegistry {
+    /// A registry that can't be read is reported to `errors` and ignored.
+    pub fn new(registry: Option<&Value>, errors: &mut Vec<String>) -> Self {
+        let Some(registry) = registry else {
+            return Self::default();
+        };
+        match Self::deserialize(registry) {
+            Ok(registry) => registry,
+            Err(error) => {
+                errors.push(format!(
+                    "The key registry from `keys.registry` is invalid: {error}"
+                ));
+                Self::default()
+            }
+        }
+    }
+
+    pub fn get(&self, id: &str) -> Option<&RegisteredKey> {
+        self.0.get(id)
+    }
+
+    /// Finds the registered message of a file that a changed message most
+    /// likely originates from, i.e. the one with the fewest edits among the
+    /// ones of the same namespace and context. Returns its key without the
+    /// namespace.
+    pub fn find_previous(
+        &self,
+        path: &str,
+        namespace: Option<&str>,
+        context: Option<&str>,
+        message: &str,
+    ) -> Option<(&str, &RegisteredKey)> {
+        self.0
+            .iter()
+            .filter(|(_, registered)| {
+                registered.path == path && registered.context.as_deref() == context
+            })
+            .filter_map(|(id, registered)| {
+                let key = match namespace {
+                    Some(namespace) => id
+                        .strip_prefix(namespace)?
+                        .strip_prefix(NAMESPACE_SEPARATOR)?,
+                    None => id,
+                };
+                if key.contains(NAMESPACE_SEPARATOR) {
+                    return None;
+                }
+
+                // Otherwise it's rather a different message than a correction
+                let distance = edit_distance(&registered.message, message);
+                let length = registered
+                    .message
+                    .chars()
+                    .count()
+                    .max(message.chars().count());
+                (distance * 3 <= length).then_some((distance, key, registered))
+            })
+            .min_by(|a, b| a.0.cmp(&b.0).then_with(|| a.1.cmp(b.1)))
+            .map(|(_, key, registered)| (key, registered))
+    }
+}
+
+/// The number of characters that need to be inserted, removed or replaced to
+/// turn `a` into `b` (Levenshtein distance).
+fn edit_distance(a: &str, b: &str) -> usize {
+    let b: Vec<char> = b.chars().collect();
+    let mut row: Vec<usize> = (0..=b.len()).collect();
+    for (i, a_char) in a.chars().enumerate() {
+        let mut previous = row[0];
+        row[0] = i + 1;
+        for (j, b_char) in b.iter().enumerate() {
+            let current = row[j + 1];
+            row[j + 1] = if a_char == *b_char {
+                previous
+            } else {
+                1 + previous.min(current).min(row[j])
+            };
+            previous = current;
+        }
+    }
+    row[b.len()]
+}
@@ -1,20 +1,48 @@
 #![allow(clippy::not_unsafe_ptr_arg_deref)]
 #![feature(box_patterns)]
 
+mod explicit_ids;
+mod helper_functions;
+mod hook_references;
+mod icu_message;
+mod jsx_message;
 mod key_generator;
-
-use rustc_hash::FxHashMap;
+mod key_registry;
+mod message_descriptors;
+mod message_values;
+mod normalize;
+mod static_eval;
+mod translator_comments;
+
+pub use explicit_ids::IdConfig;
+use explicit_ids::IdValidator;
+use helper_functions::HelperFunctions;
+use hook_references::check_hook_references;
+use icu_message::parse_message;
+use jsx_message::JsxMessage;
+use key_generator::KeyGenerator;
+pub use key_generator::{HashAlgorithm, KeyConfig, KeyMode};
+use key_registry::KeyRegistry;
+use message_descriptors::{collect_descriptor_bindings, is_descriptor_expr, MessageMarker};
+use message_values::check_message_values;
+pub use normalize::NormalizeConfig;
+use rustc_hash::{FxHashMap, FxHashSet};
 use serde::{Deserialize, Serialize};
-use swc_atoms::Wtf8Atom;
-use swc_common::{errors::HANDLER, Spanned, DUMMY_SP};
+use static_eval::{unwrap_expr, unwrap_expr_mut, StaticEvaluator, StaticString};
+use swc_atoms::{Atom, Wtf8Atom};
+use swc_common::{
+    comments::Comments, errors::HANDLER, util::take::Take, BytePos, Mark, Span, Spanned,
+    SyntaxContext, DUMMY_SP,
+};
 use swc_core::{
     common::SourceMapper, plugin::proxies::TransformPluginProgramMetadata,
     transform_common::output::experimental_emit,
 };
 use swc_ecma_ast::*;
-use swc_ecma_utils::ExprFactory;
+use swc_ecma_utils::{prepend_stmts, ExprFactory};
 use swc_ecma_visit::{VisitMut, VisitMutWith};
 use swc_plugin_macro::plugin_transform;
+use translator_comments::{comment_description, KeepComment};
 
 #[plugin_transform]
 fn next_intl_plugin(mut program: Program, data: TransformPluginProgramMetadata) -> Program {
@@ -26,9 +54,10 @@ fn next_intl_plugin(mut program: Program, data: TransformPluginProgramMetadata)
     .expect("Invalid config");
 
     let mut visitor = TransformVisitor::new(
-        config.is_development,
-        config.file_path,
+        config,
         Some(Box::new(data.source_map) as Box<dyn SourceMapper>),
+        data.comments
+            .map(|comments| Box::new(comments) as Box<dyn Comments>),
     );
     program.visit_mut_with(&mut visitor);
 
@@ -44,37 +73,170 @@ const NAMESPACE_SEPARATOR: &str = ".";
 
 #[derive(Debug, Deserialize)]
 #[serde(rename_all = "camelCase")]
-struct Config {
-    is_development: bool,
-    file_path: String,
+pub struct Config {
+    pub is_development: bool,
+    pub file_path: String,
+
+    /// Additional modules that export extracted hooks, e.g. a project-level
+    /// re-export of `useExtracted` from `@/i18n`.
+    #[serde(default)]
+    pub hooks: Vec<HookSource>,
+
+    /// How keys are generated from messages
+    #[serde(default)]
+    pub keys: KeyConfig,
+
+    /// How messages are canonicalized before their key is generated
+    #[serde(default)]
+    pub normalize: NormalizeConfig,
+
+    /// Restrictions for explicit IDs
+    #[serde(default)]
+    pub ids: IdConfig,
+}
+
+/// An export that is treated like `useExtracted` or `getExtracted`.
+#[derive(Debug, Clone, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct HookSource {
+    /// The module specifier the hook is imported from (e.g. `@/i18n`)
+    pub source: String,
+
+    /// The exported name of the hook (e.g. `useAppExtracted`)
+    pub name: String,
+
+    /// The translation hook the calls are compiled to
+    #[serde(rename = "type")]
+    pub hook_type: HookType,
+
+    /// The module the translation hook is imported from after compilation,
+    /// defaults to `next-intl` or `next-intl/server`
+    pub target: Option<String>,
 }
 
 pub struct TransformVisitor {
-    is_development: bool,
-    file_path: String,
+    config: Config,
+    key_generator: KeyGenerator,
+    key_registry: KeyRegistry,
+    id_validator: IdValidator,
     source_map: Option<Box<dyn SourceMapper>>,
+    comments: Option<Box<dyn Comments>>,
 
-    hook_local_names: FxHashMap<Id, HookType>,
+    /// Description from a comment before the statement or JSX child that is
+    /// being visited, which applies to the first message in it.
+    pending_description: Option<Wtf8Atom>,
+
+    /// Original local bindings of extracted hooks, mapped to the local name
+    /// of the translation hook they are rewritten to.
+    hook_local_names: FxHashMap<Id, Atom>,
+
+    /// Local bindings of hooks registered via `hooks`, whose signature isn't
+    /// known.
+    custom_hooks: FxHashSet<Id>,
+
+    /// Namespace and default imports of modules that export a hook, e.g.
+    /// `import * as intl from 'next-intl'`.
+    hook_namespace_imports: FxHashMap<Id, Wtf8Atom>,
+
+    /// Imports of translation hooks, each with a unique local name.
+    hook_imports: Vec<HookImport>,
 
     translator_map: FxHashMap<Id, TranslatorInfo>,
 
+    /// Objects with properties that hold translators, e.g. `labels` in
+    /// `const labels = {t, locale}`.
+    translator_props: FxHashMap<Id, FxHashMap<Atom, TranslatorInfo>>,
+
+    /// Translators returned by functions of the module, e.g. `useLabels` in
+    /// `function useLabels() { const t = useExtracted(); return {t}; }`.
+    returned_translators: FxHashMap<Id, ReturnedTranslator>,
+
+    /// Properties of `this` that hold translators, per enclosing class, e.g.
+    /// `this.t = t` in a constructor.
+    this_translators: Vec<FxHashMap<Atom, TranslatorInfo>>,
+
+    /// Local bindings of `msg` and `defineMessages`.
+    message_markers: FxHashMap<Id, MessageMarker>,
+
+    /// Local bindings of the `<Extracted>` component.
+    extracted_components: FxHashSet<Id>,
+
+    /// The outermost function that is being visited, which declares the
+    /// translator that `<Extracted>` elements are compiled to.
+    component: Option<ComponentScope>,
+
+    /// Number of translators declared for `<Extracted>` elements.
+    component_translators: usize,
+
+    /// The span of a function that is declared as a component or hook, e.g.
+    /// based on its name, for when it is visited.
+    component_hint: Option<Span>,
+
+    /// Bindings that hold message descriptors, e.g. `const labels = defineMessages({...})`.
+    descriptor_bindings: FxHashSet<Id>,
+
+    /// Module-scope constants that messages can be composed of.
+    constants: StaticEvaluator,
+
+    /// Same-module functions whose bodies are visited after the rest of the
+    /// module, once translators passed to them are known.
+    helpers: HelperFunctions,
+
+    /// The state of the scopes that deferred helpers are declared in.
+    helper_contexts: FxHashMap<Id, HelperContext>,
+
+    /// Parameters of helpers that received a translator (`true`) or another
+    /// value (`false`) at a call site.
+    helper_param_values: FxHashMap<Id, bool>,
+
+    /// Keys of the messages in this file, mapped to the message and context
+    /// they are used for. Besides generated keys, this includes explicit ones.
+    generated_keys: FxHashMap<Wtf8Atom, (Wtf8Atom, Option<Wtf8Atom>)>,
+
     /// Each statically extracted source-code usage in discovery order.
     results: Vec<SourceMessage>,
+
+    /// Problems with options of the config, which are ignored. These are
+    /// reported once the program is visited.
+    config_errors: Vec<String>,
 }
 
 impl TransformVisitor {
     pub fn new(
-        is_development: bool,
-        file_path: String,
+        config: Config,
         source_map: Option<Box<dyn SourceMapper>>,
+        comments: Option<Box<dyn Comments>>,
     ) -> Self {
+        let mut config_errors = Vec::new();
         Self {
-            is_development,
-            file_path,
+            key_generator: KeyGenerator::new(config.keys.clone(), &mut config_errors),
+            key_registry: KeyRegistry::new(config.keys.registry.as_ref(), &mut config_errors),
+            id_validator: IdValidator::new(&config.ids, &mut config_errors),
+            config,
             source_map,
+            comments,
+            pending_description: Default::default(),
             hook_local_names: Default::default(),
+            custom_hooks: Default::default(),
+            hook_namespace_imports: Default::default(),
+            hook_imports: Default::default(),
             translator_map: Default::default(),
+            translator_props: Default::default(),
+            returned_translators: Default::default(),
+            this_translators: Default::default(),
+            message_markers: Default::default(),
+            extracted_components: Default::default(),
+            component: Default::default(),
+            component_translators: Default::default(),
+            component_hint: Default::default(),
+            descriptor_bindings: Default::default(),
+            constants: Default::default(),
+            helpers: Default::default(),
+            helper_param_values: Default::default(),
+            helper_contexts: Default::default(),
+            generated_keys: Default::default(),
             results: Default::default(),
+            config_errors,
         }
     }
 
@@ -82,146 +244,334 @@ impl TransformVisitor {
         self.results.clone()
     }
 
-    fn define_translator(&mut self, name: Id, namespace: Option<Wtf8Atom>) {
-        self.translator_map
-            .insert(name, TranslatorInfo { namespace });
+    fn define_translator(&mut self, name: Id, translator: TranslatorInfo) {
+        self.translator_map.insert(name, translator);
     }
-}
-
-#[derive(Debug, Clone)]
-struct TranslatorInfo {
-    namespace: Option<Wtf8Atom>,
-}
 
-#[derive(Debug, Clone, Serialize)]
-pub struct SourceMessage {
-    pub id: Wtf8Atom,
-    pub message: Wtf8Atom,
-    pub description: Option<Wtf8Atom>,
-    pub reference: Reference,
-}
+    /// Finds the hook that `name` refers to when imported from `source`,
+    /// along with the module its translation hook is imported from.
+    fn resolve_hook(&self, source: &Wtf8Atom, name: &str) -> Option<(HookType, Wtf8Atom)> {
+        if let Some(hook_type) = HookType::from_source(source.as_bytes()) {
+            if hook_type.extracted_name() == name {
+                return Some((hook_type, source.clone()));
+            }
+        }
 
-#[derive(Debug, Clone, Serialize)]
-pub struct Reference {
-    pub path: String,
-    pub line: usize,
-}
+        self.config
+            .hooks
+            .iter()
+            .find(|hook| hook.source.as_bytes() == source.as_bytes() && hook.name == name)
+            .map(|hook| {
+                let target = hook
+                    .target
+                    .as_deref()
+                    .unwrap_or_else(|| hook.hook_type.default_target());
+                (hook.hook_type, target.into())
+            })
+    }
 
-#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
-enum HookType {
-    UseTranslation,
-    GetTranslation,
-}
+    /// Whether a module can export an extracted hook, e.g. `next-intl/server`.
+    fn is_hook_source(&self, source: &Wtf8Atom) -> bool {
+        HookType::from_source(source.as_bytes()).is_some()
+            || self
+                .config
+                .hooks
+                .iter()
+                .any(|hook| hook.source.as_bytes() == source.as_bytes())
+    }
 
-impl HookType {
-    /// The extracted hook name we look for in imports (e.g. `useExtracted`)
-    fn extracted_name(self) -> &'static str {
-        match self {
-            HookType::UseTranslation => "useExtracted",
-            HookType::GetTranslation => "getExtracted",
+    /// The unique local name of the translation hook from `target`.
+    fn hook_local_name(&self, hook_type: HookType, target: &Wtf8Atom) -> Atom {
+        let mut count = 0;
+        for import in &self.hook_imports {
+            if import.hook_type == hook_type {
+                if import.target == *target {
+                    return import.local.sym.clone();
+                }
+                count += 1;
+            }
         }
+
+        format!("{}${}", hook_type.target_name(), count + 1).into()
     }
 
-    /// The real hook name we import from next-intl (e.g. `useTranslations`)
-    fn target_name(self) -> &'static str {
-        match self {
-            HookType::UseTranslation => "useTranslations",
-            HookType::GetTranslation => "getTranslations",
+    /// Returns the import of the translation hook from `target`, allocating
+    /// a unique local name on first use.
+    fn hook_import(&mut self, hook_type: HookType, target: Wtf8Atom, ctxt: SyntaxContext) -> usize {
+        if let Some(index) = self
+            .hook_imports
+            .iter()
+            .position(|import| import.hook_type == hook_type && import.target == target)
+        {
+            return index;
         }
+
+        self.hook_imports.push(HookImport {
+            hook_type,
+            local: Ident::new(self.hook_local_name(hook_type, &target), DUMMY_SP, ctxt),
+            target,
+            is_declared: false,
+        });
+        self.hook_imports.len() - 1
     }
 
-    /// The unique local identifier used to avoid conflicts with existing imports
-    fn local_name(self) -> swc_atoms::Atom {
-        match self {
-            HookType::UseTranslation => "useTranslations$1".into(),
-            HookType::GetTranslation => "getTranslations$1".into(),
+    /// Records an extracted message and returns the key that the call site
+    /// uses, which doesn't include the namespace.
+    fn add_message(
+        &mut self,
+        message: &StaticString,
+        explicit_id: Option<Wtf8Atom>,
+        namespace: Option<&Wtf8Atom>,
+        details: MessageDetails,
+        span: Span,
+    ) -> Wtf8Atom {
+        let MessageDetails {
+            description,
+            context,
+            previous_message,
+        } = details;
+        let context = self.message_context(context, description.as_ref());
+        let call_key = match explicit_id {
+            Some(id) => {
+                self.use_explicit_key(&id, &message.value, context.as_ref(), namespace, span);
+                id
+            }
+            None => self.generate_key(&message.value, context.as_ref(), namespace, span),
+        };
+        let full_key = full_key(namespace, &call_key);
+        let line = self
+            .source_map
+            .as_ref()
+            .map_or(0, |sm| sm.lookup_char_pos(span.lo).line);
+        let new_reference = Reference {
+            path: self.config.file_path.clone(),
+            line,
+        };
+
+        self.results.push(SourceMessage {
+            id: full_key,
+            message: message.value.clone(),
+            description,
+            context,
+            constants: message.constants.clone(),
+            previous_message,
+            reference: new_reference,
+        });
+
+        call_key
+    }
+
+    /// Returns the ID if it can be used as a key and reports it otherwise.
+    fn validate_explicit_id(&self, id: Wtf8Atom, span: Span) -> Option<Wtf8Atom> {
+        match self.id_validator.validate(&id.to_string_lossy()) {
+            Ok(()) => Some(id),
+            Err(message) => {
+                emit_error(span, &message);
+                None
+            }
         }
     }
-}
 
-impl VisitMut for TransformVisitor {
-    fn visit_mut_call_expr(&mut self, call: &mut CallExpr) {
-        let mut is_translator_call = false;
-        let mut namespace = None;
+    /// The context that is included in the key of a message, which is either
+    /// set explicitly or, if enabled, the description.
+    fn message_context(
+        &self,
+        context: Option<Wtf8Atom>,
+        description: Option<&Wtf8Atom>,
+    ) -> Option<Wtf8Atom> {
+        context.or_else(|| {
+            description
+                .filter(|_| self.config.keys.hash_description)
+                .cloned()
+        })
+    }
 
-        // Handle Identifier case: t("message")
-        match &call.callee {
-            Callee::Expr(box Expr::Ident(ident)) => {
-                if let Some(translator) = self.translator_map.get(&ident.to_id()) {
-                    is_translator_call = true;
-                    namespace = translator.namespace.clone();
+    /// Generates the key of a message and checks that it doesn't collide with
+    /// the key of a different message in this file or in the key registry.
+    fn generate_key(
+        &mut self,
+        message: &Wtf8Atom,
+        context: Option<&Wtf8Atom>,
+        namespace: Option<&Wtf8Atom>,
+        span: Span,
+    ) -> Wtf8Atom {
+        let mut length = self.config.keys.length;
+        let mut key: Wtf8Atom = self.key_generator.generate(message, context).into();
+        loop {
+            let id = full_key(namespace, &key);
+            let Some((other_message, other_source)) = self.key_collision(&id, message, context)
+            else {
+                self.generated_keys
+                    .insert(id, (message.clone(), context.cloned()));
+                return key;
+            };
+
+            if self.config.keys.lengthen_collisions {
+                length += 1;
+                let longer = self
+                    .key_generator
+                    .generate_with_length(message, context, length)
+                    .into();
+                // Otherwise the full hash is already used
+                if longer != key {
+                    key = longer;
+                    continue;
                 }
             }
 
-            Callee::Expr(box Expr::Member(MemberExpr {
-                obj: box Expr::Ident(obj),
-                prop: MemberProp::Ident(prop),
-                ..
-            })) => {
-                if matches!(&*prop.sym, "rich" | "markup" | "has") {
-                    if let Some(translator) = self.translator_map.get(&obj.to_id()) {
-                        is_translator_call = true;
-                        namespace = translator.namespace.clone();
-                    }
-                }
+            emit_error(
+                span,
+                &format!(
+                    "The key `{}` of \"{}\" collides with the key of \"{}\" in {}. Provide \
+                     an explicit `id` for one of the messages or enable \
+                     `keys.lengthenCollisions`.",
+                    id.to_string_lossy(),
+                    message.to_string_lossy(),
+                    other_message,
+                    other_source
+                ),
+            );
+            return key;
+        }
+    }
+
+    /// Records an explicit key, e.g. from `t({id: 'greeting', ...})` or a
+    /// `keep` comment, and checks that it isn't used for a different message.
+    fn use_explicit_key(
+        &mut self,
+        key: &Wtf8Atom,
+        message: &Wtf8Atom,
+        context: Option<&Wtf8Atom>,
+        namespace: Option<&Wtf8Atom>,
+        span: Span,
+    ) {
+        let id = full_key(namespace, key);
+        match self.key_collision(&id, message, context) {
+            Some((other_message, other_source)) => emit_error(
+                span,
+                &format!(
+                    "The key `{}` of \"{}\" is already used for \"{}\" in {}. Messages with \
+                     the same key need to have the same text, otherwise use a different key \
+                     for one of them.",
+                    id.to_string_lossy(),
+                    message.to_string_lossy(),
+                    other_message,
+                    other_source
+                ),
+            ),
+            None => {
+                self.generated_keys
+                    .entry(id)
+                    .or_insert_with(|| (message.clone(), context.cloned()));
             }
+        }
+    }
 
-            _ => {}
+    /// Returns the message and source of a different message with the same
+    /// key. Registered keys of this file are outdated, since it's being
+    /// extracted again.
+    fn key_collision(
+        &self,
+        id: &Wtf8Atom,
+        message: &Wtf8Atom,
+        context: Option<&Wtf8Atom>,
+    ) -> Option<(String, String)> {
+        if let Some((other_message, other_context)) = self.generated_keys.get(id) {
+            return (other_message != message || other_context.as_ref() != context).then(|| {
+                (
+                    other_message.to_string_lossy().into_owned(),
+                    "this file".to_string(),
+                )
+            });
         }
 
-        if is_translator_call {
-            let arg0 = call.args.first();
+        let registered = self.key_registry.get(&id.to_string_lossy())?;
+        let context = context.map(|context| context.to_string_lossy());
+        (registered.path != self.config.file_path
+            && (registered.message != message.to_string_lossy()
+                || registered.context.as_deref() != context.as_deref()))
+        .then(|| (registered.message.clone(), registered.path.clone()))
+    }
 
-            let mut message_text = None;
+    /// Extracts the message of a translator call like `t('Hello!')` or
+    /// `t.rich(...)` and rewrites its arguments.
+    fn transform_translator_call(
+        &mut self,
+        callee: &Expr,
+        args: &mut Vec<ExprOrSpread>,
+        span: Span,
+    ) {
+        let Some(TranslatorInfo { namespace, method }) = self.resolve_translator(callee) else {
+            return;
+        };
+        let is_has_call = method.as_deref() == Some("has");
+
+        if args
+            .first()
+            .is_some_and(|arg| is_descriptor_expr(&arg.expr, &self.descriptor_bindings))
+        {
+            self.rewrite_descriptor_call(args, is_has_call, namespace.is_some());
+        } else {
+            let arg0 = args.first();
+
+            let mut message = None;
             let mut explicit_id = None;
             let mut description = None;
+            let mut context = None;
             let mut values_node = None;
             let mut formats_node = None;
 
             if let Some(arg0) = arg0 {
-                match &*arg0.expr {
+                match unwrap_expr(&arg0.expr) {
                     // Handle object syntax: t({id: 'key', message: 'text'})
-                    Expr::Object(ObjectLit { props, .. }) => {
-                        for prop in props {
-                            if let PropOrSpread::Prop(box Prop::KeyValue(KeyValueProp {
-                                key: PropName::Ident(key),
-                                value,
-                                ..
-                            })) = prop
-                            {
-                                if key.sym == "id" {
-                                    let static_id = extract_static_string(value);
-                                    if let Some(static_id) = static_id {
-                                        explicit_id = Some(static_id);
-                                    }
-                                } else if key.sym == "message" {
-                                    let static_message = extract_static_string(value);
-                                    if let Some(static_message) = static_message {
-                                        message_text = Some(static_message);
-                                    } else {
-                                        warn_dynamic_expression(value);
-                                    }
-                                } else if key.sym == "description" {
-                                    let static_description = extract_static_string(value);
-                                    if let Some(static_description) = static_description {
-                                        description = Some(static_description);
-                                    } else {
-                                        warn_dynamic_expression(value);
-                                    }
-                                } else if key.sym == "values" {
-                                    values_node = Some(value.clone());
-                                } else if key.sym == "formats" {
-                                    formats_node = Some(value.clone());
+                    Expr::Object(object) => {
+                        for (key, value) in message_object_props(object) {
+                            if key == "id" {
+                                let static_id = self.constants.eval(&value);
+                                if let Some(static_id) = static_id {
+                                    explicit_id =
+                                        self.validate_explicit_id(static_id.value, value.span());
+                                } else {
+                                    emit_warning(
+                                        value.span(),
+                                        "IDs need to be statically analyzable, otherwise they are \
+                                         ignored and a key is generated from the message.",
+                                    );
                                 }
+                            } else if key == "message" {
+                                let static_messages = self.extract_static_messages(&value);
+                                if let Some(static_messages) = static_messages {
+                                    message = Some((value, static_messages));
+                                } else {
+                                    warn_dynamic_expression(&value);
+                                }
+                            } else if key == "description" {
+                                let static_description = self.constants.eval(&value);
+                                if let Some(static_description) = static_description {
+                                    description = Some(static_description.value);
+                                } else {
+                                    warn_dynamic_expression(&value);
+                                }
+                            } else if key == "context" {
+                                let static_context = self.constants.eval(&value);
+                                if let Some(static_context) = static_context {
+                                    context = Some(static_context.value);
+                                } else {
+                                    warn_dynamic_expression(&value);
+                                }
+                            } else if key == "values" {
+                                values_node = Some(value);
+                            } else if key == "formats" {
+                                formats_node = Some(value);
                             }
                         }
                     }
 
                     // Handle string syntax: t('text') or t(`text`)
                     _ => {
-                        let static_string = extract_static_string(&arg0.expr);
-                        if let Some(static_string) = static_string {
-                            message_text = Some(static_string);
+                        let static_messages = self.extract_static_messages(&arg0.expr);
+                        if let Some(static_messages) = static_messages {
+                            message = Some((arg0.expr.clone(), static_messages));
                         } else {
                             // Dynamic expression (Identifier, CallExpression, BinaryExpression,
                             // etc.)
@@ -231,271 +581,2145 @@ impl VisitMut for TransformVisitor {
                 }
             }
 
-            if let Some(message_text) = message_text {
-                let call_key = explicit_id
-                    .unwrap_or_else(|| key_generator::KeyGenerator::generate(&message_text).into());
-                let full_key = namespace.map_or(call_key.clone(), |namespace| {
-                    [&*namespace.to_string_lossy(), &*call_key.to_string_lossy()]
-                        .join(NAMESPACE_SEPARATOR)
-                        .into()
-                });
-                let line = self
-                    .source_map
-                    .as_ref()
-                    .map_or(0, |sm| sm.lookup_char_pos(call.span.lo).line);
-                let new_reference = Reference {
-                    path: self.file_path.clone(),
-                    line,
-                };
-
-                self.results.push(SourceMessage {
-                    id: full_key.clone(),
-                    message: message_text.clone(),
-                    description,
-                    reference: new_reference,
-                });
+            // Leading comments like `// i18n: ...` unless there's a `description`
+            let comment_description = self.leading_comment_description(span);
+            if description.is_none() {
+                description = comment_description;
+            }
 
-                // Transform the argument based on type
-                match &mut *call.args[0].expr {
-                    Expr::Lit(Lit::Str(s)) => {
-                        s.value = call_key;
-                        s.raw = None;
+            if let Some((message_node, message_texts)) =
+                message.filter(|(message_node, message_texts)| {
+                    if explicit_id.is_some() && message_texts.len() > 1 {
+                        emit_error(
+                            message_node.span(),
+                            "A conditional message can't be combined with an explicit \
+                             `id`, as every branch needs its own key.",
+                        );
+                        return false;
                     }
+                    true
+                })
+            {
+                // Values are passed within the object syntax or as the second
+                // argument, unless they're spread into the call
+                if !is_has_call {
+                    match (&values_node, args.get(1)) {
+                        (Some(values), _) => {
+                            check_message_values(&message_node, &message_texts, Some(values))
+                        }
+                        (
+                            None,
+                            Some(ExprOrSpread {
+                                spread: Some(_), ..
+                            }),
+                        ) => {}
+                        (None, values) => check_message_values(
+                            &message_node,
+                            &message_texts,
+                            values.map(|values| &*values.expr),
+                        ),
+                    }
+                }
 
-                    Expr::Tpl(tpl) => {
-                        // Replace template literal with string literal
-                        *call.args[0].expr = Expr::Lit(Lit::Str(Str {
-                            span: tpl.span,
-                            value: call_key,
-                            raw: None,
-                        }));
+                // Keeps the key of the previous text: t(/* keep: Helo! */ 'Hello!')
+                let mut previous_message = None;
+                if let Some((keep, comment_span)) = self.keep_comment(message_node.span().lo) {
+                    if explicit_id.is_some() || message_texts.len() > 1 {
+                        emit_error(
+                            comment_span,
+                            "A `keep` comment can't be combined with an explicit `id` or a \
+                             conditional message.",
+                        );
+                    } else {
+                        match keep {
+                            KeepComment::Bare => {
+                                let text = self
+                                    .config
+                                    .normalize
+                                    .apply(&message_texts[0].value.to_string_lossy());
+                                let namespace = namespace
+                                    .as_ref()
+                                    .map(|namespace| namespace.to_string_lossy());
+                                let context =
+                                    context.as_ref().map(|context| context.to_string_lossy());
+                                match self.key_registry.find_previous(
+                                    &self.config.file_path,
+                                    namespace.as_deref(),
+                                    context.as_deref(),
+                                    &text,
+                                ) {
+                                    Some((previous_id, registered)) => {
+                                        explicit_id = Some(previous_id.into());
+                                        if registered.message != text {
+                                            previous_message =
+                                                Some(registered.message.as_str().into());
+                                        }
+                                    }
+                                    None => emit_error(
+                                        comment_span,
+                                        "No previous message of this file is similar to this \
+                                         one. Reference the previous text, e.g. `/* keep: Helo! \
+                                         */`, or its key, e.g. `/* keep-id: OpKKos */`.",
+                                    ),
+                                }
+                            }
+                            KeepComment::PreviousMessage(previous) => {
+                                let previous: Wtf8Atom =
+                                    self.config.normalize.apply(&previous).into();
+                                let context =
+                                    self.message_context(context.clone(), description.as_ref());
+                                explicit_id = Some(
+                                    self.key_generator
+                                        .generate(&previous, context.as_ref())
+                                        .into(),
+                                );
+                                previous_message = Some(previous);
+                            }
+                            KeepComment::PreviousId(previous_id) => {
+                                match self.id_validator.validate_key(&previous_id) {
+                                    Ok(()) => explicit_id = Some(previous_id.into()),
+                                    Err(message) => emit_error(comment_span, &message),
+                                }
+                            }
+                        }
                     }
+                }
+
+                let call_keys = message_texts
+                    .iter()
+                    .map(|message_text| {
+                        self.add_message(
+                            message_text,
+                            explicit_id.clone(),
+                            namespace.as_ref(),
+                            MessageDetails {
+                                description: description.clone(),
+                                context: context.clone(),
+                                previous_message: previous_message.clone(),
+                            },
+                            span,
+                        )
+                    })
+                    .collect::<Vec<_>>();
+
+                // Each static string is replaced with its key, e.g. in both
+                // branches of `t(isAdmin ? 'Delete everything' : 'Delete')`
+                let mut key_node = message_node.clone();
+                let mut call_keys = call_keys.into_iter();
+                replace_static_strings(&mut key_node, &mut |message| {
+                    *message = Expr::Lit(Lit::Str(Str {
+                        span: message.span(),
+                        value: call_keys.next().unwrap(),
+                        raw: None,
+                    }));
+                });
 
+                // Transform the argument based on type
+                match unwrap_expr_mut(&mut args[0].expr) {
                     Expr::Object(ObjectLit { span: obj_span, .. }) => {
                         // Transform object expression to individual parameters
                         // Replace the object with the key as first argument
 
-                        *call.args[0].expr = Expr::Lit(Lit::Str(Str {
-                            span: *obj_span,
-                            value: call_key,
-                            raw: None,
-                        }));
+                        if let Expr::Lit(Lit::Str(key)) = &mut *key_node {
+                            key.span = *obj_span;
+                        }
+                        args[0].expr = key_node;
 
                         // Add values as second argument if present
                         if let Some(values_node) = values_node {
-                            if call.args.len() < 2 {
-                                call.args.push(ExprOrSpread {
+                            if args.len() < 2 {
+                                args.push(ExprOrSpread {
                                     spread: None,
                                     expr: values_node.clone(),
                                 });
                             } else {
-                                call.args[1].expr = values_node.clone();
+                                args[1].expr = values_node.clone();
                             }
                         }
 
                         // Add formats as third argument if present
                         if let Some(formats_node) = formats_node {
-                            while call.args.len() < 2 {
-                                call.args.push(Expr::undefined(DUMMY_SP).as_arg());
+                            while args.len() < 2 {
+                                args.push(Expr::undefined(DUMMY_SP).as_arg());
                             }
 
-                            if call.args.len() < 3 {
-                                call.args.push(ExprOrSpread {
+                            if args.len() < 3 {
+                                args.push(ExprOrSpread {
                                     spread: None,
                                     expr: formats_node.clone(),
                                 });
                             } else {
-                                call.args[2].expr = formats_node.clone();
+                                args[2].expr = formats_node.clone();
                             }
                         }
                     }
 
-                    _ => {}
+                    _ => {
+                        args[0].expr = key_node;
+                    }
                 }
 
-                // Check if this is a t.has call (which doesn't need fallback)
-                let is_has_call = match &call.callee {
-                    Callee::Expr(box Expr::Member(MemberExpr {
-                        prop: MemberProp::Ident(prop),
-                        ..
-                    })) => prop.sym == "has",
-                    _ => false,
-                };
-
                 // Add fallback message as 4th parameter in development mode (except for t.has)
-                if self.is_development && !is_has_call {
-                    while call.args.len() < 3 {
-                        call.args.push(Expr::undefined(DUMMY_SP).as_arg());
+                if self.config.is_development && !is_has_call {
+                    while args.len() < 3 {
+                        args.push(Expr::undefined(DUMMY_SP).as_arg());
                     }
 
-                    call.args.push(
-                        Str {
-                            span: DUMMY_SP,
-                            value: message_text,
-                            raw: None,
-                        }
-                        .as_arg(),
-                    );
+                    let mut message_texts = message_texts.into_iter();
+                    if message_texts.len() == 1 {
+                        args.push(Lit::Str(message_texts.next().unwrap().value.into()).as_arg());
+                    } else {
+                        // Each branch passes its key along with its fallback, so that the
+                        // condition is only evaluated once:
+                        // `t(...(isAdmin ? [key, values, formats, fallback] : [...]))`
+                        let rest = args.split_off(1);
+                        let mut key_node = args.pop().unwrap().expr;
+                        replace_static_strings(&mut key_node, &mut |key| {
+                            let mut elems = vec![Some(key.take().as_arg())];
+                            elems.extend(rest.iter().cloned().map(Some));
+                            elems.push(Some(
+                                Lit::Str(message_texts.next().unwrap().value.into()).as_arg(),
+                            ));
+                            *key = Expr::Array(ArrayLit {
+                                span: DUMMY_SP,
+                                elems,
+                            });
+                        });
+                        args.push(ExprOrSpread {
+                            spread: Some(DUMMY_SP),
+                            expr: key_node,
+                        });
+                    }
                 }
             }
         }
+    }
 
-        call.visit_mut_children_with(self);
+    /// Reads a description from a comment directly before a call or, for the
+    /// first message of a statement, before the statement.
+    fn leading_comment_description(&mut self, span: Span) -> Option<Wtf8Atom> {
+        let pending_description = self.pending_description.take();
+        self.comment_description(span.lo, false)
+            .or(pending_description)
     }
 
-    fn visit_mut_module(&mut self, module: &mut Module) {
-        for import in module.body.iter_mut() {
-            if let ModuleItem::ModuleDecl(ModuleDecl::Import(import)) = import {
-                match import.src.value.as_bytes() {
-                    b"next-intl" => {
-                        for specifier in &mut import.specifiers {
-                            if let ImportSpecifier::Named(named_spec) = specifier {
-                                let orig_name = named_spec
-                                    .imported
-                                    .as_ref()
-                                    .and_then(|x| match x {
-                                        ModuleExportName::Ident(ident) => Some(ident.sym.clone()),
-                                        ModuleExportName::Str(..) => None,
-                                    })
-                                    .unwrap_or_else(|| named_spec.local.sym.clone())
-                                    .clone();
-
-                                if orig_name == HookType::UseTranslation.extracted_name() {
-                                    self.hook_local_names
-                                        .insert(named_spec.local.to_id(), HookType::UseTranslation);
-
-                                    named_spec.imported = Some(ModuleExportName::Ident(
-                                        HookType::UseTranslation.target_name().into(),
-                                    ));
-                                    named_spec.local = Ident::new(
-                                        HookType::UseTranslation.local_name(),
-                                        DUMMY_SP,
-                                        named_spec.local.ctxt,
-                                    );
-                                }
-                            }
-                        }
-                    }
+    /// Checks if a statement is a translator call, optionally assigned to a
+    /// variable, e.g. `t('Cart')` or `const title = t('Cart')`.
+    fn is_translator_call_stmt(&self, stmt: &Stmt) -> bool {
+        let is_translator_call = |expr: &Expr| match unwrap_expr(expr) {
+            Expr::Call(CallExpr {
+                callee: Callee::Expr(callee),
+                ..
+            })
+            | Expr::OptChain(OptChainExpr {
+                base: box OptChainBase::Call(OptCall { callee, .. }),
+                ..
+            }) => self.resolve_translator(callee).is_some(),
+            _ => false,
+        };
+
+        match stmt {
+            Stmt::Expr(ExprStmt { expr, .. }) => is_translator_call(expr),
+            Stmt::Decl(Decl::Var(var)) => var
+                .decls
+                .iter()
+                .any(|decl| decl.init.as_deref().is_some_and(is_translator_call)),
+            _ => false,
+        }
+    }
 
-                    b"next-intl/server" => {
-                        for specifier in &mut import.specifiers {
-                            if let ImportSpecifier::Named(named_spec) = specifier {
-                                let orig_name = named_spec
-                                    .imported
-                                    .as_ref()
-                                    .and_then(|x| match x {
-                                        ModuleExportName::Ident(ident) => Some(ident.sym.clone()),
-                                        ModuleExportName::Str(_) => None,
-                                    })
-                                    .unwrap_or_else(|| named_spec.local.sym.clone())
-                                    .clone();
-
-                                if orig_name == HookType::GetTranslation.extracted_name() {
-                                    self.hook_local_names
-                                        .insert(named_spec.local.to_id(), HookType::GetTranslation);
-
-                                    named_spec.imported = Some(ModuleExportName::Ident(
-                                        HookType::GetTranslation.target_name().into(),
-                                    ));
-                                    named_spec.local = Ident::new(
-                                        HookType::GetTranslation.local_name(),
-                                        DUMMY_SP,
-                                        named_spec.local.ctxt,
-                                    );
-                                }
-                            }
-                        }
-                    }
+    /// Finds a `/* keep */` comment directly before a message.
+    fn keep_comment(&self, pos: BytePos) -> Option<(KeepComment, Span)> {
+        self.comments
+            .as_ref()?
+            .get_leading(pos)?
+            .iter()
+            .rev()
+            .find_map(|comment| Some((KeepComment::parse(comment)?, comment.span)))
+    }
+
+    /// Reads a description from the comments that are attached to `pos`.
+    fn comment_description(&self, pos: BytePos, is_trailing: bool) -> Option<Wtf8Atom> {
+        let comments = self.comments.as_ref()?;
+        let comments = if is_trailing {
+            comments.get_trailing(pos)?
+        } else {
+            comments.get_leading(pos)?
+        };
+        comments
+            .iter()
+            .rev()
+            .find_map(comment_description)
+            .map(Into::into)
+    }
+
+    /// Finds the translator that an expression refers to, e.g. `t`,
+    /// `t?.['rich']`, `labels.t` or `this.t`.
+    fn resolve_translator(&self, expr: &Expr) -> Option<TranslatorInfo> {
+        match unwrap_expr(expr) {
+            Expr::Ident(ident) => self.translator_map.get(&ident.to_id()).cloned(),
+
+            Expr::Member(member)
+            | Expr::OptChain(OptChainExpr {
+                base: box OptChainBase::Member(member),
+                ..
+            }) => {
+                let prop = member_prop_name(&member.prop)?;
 
-                    _ => {}
+                let props = match unwrap_expr(&member.obj) {
+                    Expr::This(_) => self.this_translators.last(),
+                    obj => self.resolve_translator_props(obj),
+                };
+                if let Some(translator) = props.and_then(|props| props.get(&prop)) {
+                    return Some(translator.clone());
+                }
+
+                if !matches!(&*prop, "rich" | "markup" | "has") {
+                    return None;
+                }
+                let translator = self.resolve_translator(&member.obj)?;
+                if translator.method.is_some() {
+                    return None;
                 }
+                Some(TranslatorInfo {
+                    method: Some(prop),
+                    ..translator
+                })
             }
+
+            Expr::Call(call)
+            | Expr::Await(AwaitExpr {
+                arg: box Expr::Call(call),
+                ..
+            }) => match self.returned_translator(call)? {
+                ReturnedTranslator::Translator(translator) => Some(translator.clone()),
+                ReturnedTranslator::Props(_) => None,
+            },
+
+            _ => None,
         }
+    }
 
-        module.visit_mut_children_with(self);
+    /// Finds the properties holding translators of an object that an
+    /// expression refers to, e.g. `labels` or `useLabels()`.
+    fn resolve_translator_props(&self, expr: &Expr) -> Option<&FxHashMap<Atom, TranslatorInfo>> {
+        match unwrap_expr(expr) {
+            Expr::Ident(obj) => self.translator_props.get(&obj.to_id()),
+            Expr::Call(call)
+            | Expr::Await(AwaitExpr {
+                arg: box Expr::Call(call),
+                ..
+            }) => match self.returned_translator(call)? {
+                ReturnedTranslator::Props(props) => Some(props),
+                ReturnedTranslator::Translator(_) => None,
+            },
+            _ => None,
+        }
     }
 
-    fn visit_mut_var_declarator(&mut self, node: &mut VarDeclarator) {
-        if let Some(name) = node.name.as_ident() {
-            let mut call_expr = None;
-
-            // Handle direct CallExpression: const t = useExtracted();
-
-            if let Some(init) = &mut node.init {
-                match &mut **init {
-                    Expr::Call(init_call) => {
-                        if let Callee::Expr(box Expr::Ident(callee)) = &init_call.callee {
-                            if let Some(hook_type) = self.hook_local_names.get(&callee.to_id()) {
-                                init_call.callee = Callee::Expr(
-                                    Ident::new(hook_type.local_name(), DUMMY_SP, callee.ctxt)
-                                        .into(),
-                                );
-                                call_expr = Some(init_call);
-                            }
-                        }
-                    }
+    /// Looks up what a call to a function of the module returns, e.g.
+    /// `useLabels()`.
+    fn returned_translator(&self, call: &CallExpr) -> Option<&ReturnedTranslator> {
+        let Callee::Expr(callee) = &call.callee else {
+            return None;
+        };
+        let Expr::Ident(callee) = unwrap_expr(callee) else {
+            return None;
+        };
+        self.returned_translators.get(&callee.to_id())
+    }
 
-                    Expr::Await(AwaitExpr {
-                        arg: box Expr::Call(arg),
-                        ..
-                    }) => {
-                        if let CallExpr {
-                            callee: Callee::Expr(box Expr::Ident(callee)),
-                            ..
-                        } = &*arg
-                        {
-                            if let Some(hook_type) = self.hook_local_names.get(&callee.to_id()) {
-                                arg.callee = Callee::Expr(
-                                    Ident::new(hook_type.local_name(), DUMMY_SP, callee.ctxt)
-                                        .into(),
-                                );
-                                call_expr = Some(arg);
-                            }
-                        }
-                    }
+    /// Records the translator or the properties holding translators that a
+    /// function returns with its last statement, e.g. `return t` or
+    /// `return {t, locale}`.
+    fn define_returned_translator(&mut self, name: Id, body: Option<&BlockStmt>) {
+        let Some(Stmt::Return(ReturnStmt { arg: Some(arg), .. })) =
+            body.and_then(|body| body.stmts.last())
+        else {
+            return;
+        };
+
+        let returned = if let Some(translator) = self.resolve_translator(arg) {
+            ReturnedTranslator::Translator(translator)
+        } else if let Expr::Object(object) = unwrap_expr(arg) {
+            ReturnedTranslator::Props(self.collect_translator_props(object))
+        } else if let Some(props) = self.resolve_translator_props(arg) {
+            ReturnedTranslator::Props(props.clone())
+        } else {
+            return;
+        };
+
+        if !matches!(&returned, ReturnedTranslator::Props(props) if props.is_empty()) {
+            self.returned_translators.insert(name, returned);
+        }
+    }
+
+    /// Returns the translator that an initializer or assigned value creates
+    /// or refers to, e.g. `useExtracted()`, `await getExtracted()`, `t` or
+    /// `useLabels()`.
+    fn translator_from_value(&mut self, value: &mut Expr) -> Option<TranslatorInfo> {
+        if let Expr::Call(call)
+        | Expr::Await(AwaitExpr {
+            arg: box Expr::Call(call),
+            ..
+        }) = value
+        {
+            if let Some(translator) = self.rewrite_hook_call(call) {
+                return Some(translator);
+            }
+        }
+        self.resolve_translator(value)
+    }
 
-                    _ => {}
+    /// Records the properties of an object literal that hold translators,
+    /// e.g. `{t, locale}` or `{translate: t, rich: t.rich}`.
+    fn define_translator_props(&mut self, name: Id, object: &ObjectLit) {
+        let props = self.collect_translator_props(object);
+        if !props.is_empty() {
+            self.translator_props.insert(name, props);
+        }
+    }
+
+    fn collect_translator_props(&self, object: &ObjectLit) -> FxHashMap<Atom, TranslatorInfo> {
+        let mut props = FxHashMap::default();
+        for prop in &object.props {
+            let (key, translator) = match prop {
+                PropOrSpread::Prop(box Prop::Shorthand(ident)) => (
+                    ident.sym.clone(),
+                    self.translator_map.get(&ident.to_id()).cloned(),
+                ),
+                PropOrSpread::Prop(box Prop::KeyValue(KeyValueProp { key, value })) => {
+                    let key = match key {
+                        PropName::Ident(key) => key.sym.clone(),
+                        PropName::Str(key) => match key.value.as_atom() {
+                            Some(key) => key.clone(),
+                            None => continue,
+                        },
+                        _ => continue,
+                    };
+                    (key, self.resolve_translator(value))
                 }
+                _ => continue,
+            };
+
+            if let Some(translator) = translator {
+                props.insert(key, translator);
             }
+        }
+        props
+    }
 
-            if let Some(call_expr) = call_expr {
-                let namespace = call_expr.args.first().and_then(|arg| match &*arg.expr {
-                    Expr::Lit(Lit::Str(s)) => Some(s.value.clone()),
-                    Expr::Object(ObjectLit { props, .. }) => props.iter().find_map(|prop| {
-                        let prop = prop.as_prop()?.as_key_value()?;
-                        match &prop.key {
-                            PropName::Ident(ident) => {
-                                if ident.sym == "namespace" {
-                                    Some(extract_static_string(&prop.value))
-                                } else {
-                                    None
-                                }
-                            }
-                            _ => None,
-                        }
-                    })?,
-                    _ => None,
-                });
+    /// Binds destructured translator methods or translator properties, e.g.
+    /// `const {rich} = t`, `const {t} = labels` or `const {t} = useLabels()`.
+    fn define_destructured_translators(&mut self, pat: &ObjectPat, init: &Expr) {
+        let translator = self.resolve_translator(init);
+        let props = self.resolve_translator_props(init).cloned();
+
+        for prop in &pat.props {
+            let (key, local) = match prop {
+                ObjectPatProp::Assign(AssignPatProp {
+                    key, value: None, ..
+                }) => (key.sym.clone(), key.to_id()),
+                ObjectPatProp::KeyValue(KeyValuePatProp {
+                    key: PropName::Ident(key),
+                    value: box Pat::Ident(local),
+                }) => (key.sym.clone(), local.to_id()),
+                _ => continue,
+            };
+
+            let binding = match (&translator, &props) {
+                (
+                    Some(TranslatorInfo {
+                        namespace,
+                        method: None,
+                    }),
+                    _,
+                ) if matches!(&*key, "rich" | "markup" | "has") => Some(TranslatorInfo {
+                    namespace: namespace.clone(),
+                    method: Some(key),
+                }),
+                (_, Some(props)) => props.get(&key).cloned(),
+                _ => None,
+            };
+
+            if let Some(binding) = binding {
+                self.define_translator(local, binding);
+            }
+        }
+    }
+
+    /// Compiles `<Extracted>Hello <b>{name}</b></Extracted>` to a `t.rich`
+    /// call with the translator of the enclosing component.
+    fn compile_extracted_element(&mut self, element: &JSXElement) -> Option<Expr> {
+        const EXTRACTED_PROPS_ERROR: &str =
+            "Only the `id`, `description` and `context` props can be passed to `<Extracted>`.";
+
+        let JSXElementName::Ident(name) = &element.opening.name else {
+            return None;
+        };
+        if !self.extracted_components.contains(&name.to_id()) {
+            return None;
+        }
 
-                self.define_translator(name.to_id(), namespace)
+        let mut explicit_id = None;
+        let mut description = None;
+        let mut context = None;
+        for attr in &element.opening.attrs {
+            let JSXAttrOrSpread::JSXAttr(JSXAttr {
+                name: JSXAttrName::Ident(attr_name),
+                value: Some(value),
+                ..
+            }) = attr
+            else {
+                emit_error(attr.span(), EXTRACTED_PROPS_ERROR);
+                return None;
+            };
+
+            let value = match value {
+                JSXAttrValue::Str(value) => Some(value.value.clone()),
+                JSXAttrValue::JSXExprContainer(JSXExprContainer {
+                    expr: JSXExpr::Expr(expr),
+                    ..
+                }) => extract_static_string(expr),
+                _ => None,
+            };
+            let Some(value) = value else {
+                emit_error(
+                    attr.span(),
+                    &format!(
+                        "The `{}` prop of `<Extracted>` needs to be a static string.",
+                        attr_name.sym
+                    ),
+                );
+                return None;
+            };
+
+            match &*attr_name.sym {
+                "id" => explicit_id = self.validate_explicit_id(value, attr.span()),
+                "description" => description = Some(value),
+                "context" => context = Some(value),
+                _ => {
+                    emit_error(attr.span(), EXTRACTED_PROPS_ERROR);
+                    return None;
+                }
             }
         }
 
-        node.visit_mut_children_with(self);
+        let JsxMessage { message, values } = JsxMessage::from_children(&element.children)?;
+        if message.is_empty() {
+            emit_error(element.span, "`<Extracted>` needs to contain a message.");
+            return None;
+        }
+
+        let Some(component) = self
+            .component
+            .as_mut()
+            .filter(|component| component.is_component || component.is_async)
+        else {
+            emit_error(
+                element.span,
+                "`<Extracted>` can only be used within function components, hooks and async \
+                 functions, since these can retrieve a translator.",
+            );
+            return None;
+        };
+        let translator = component
+            .translator
+            .get_or_insert_with(|| {
+                self.component_translators += 1;
+                Ident::new(
+                    format!("t${}", self.component_translators).into(),
+                    DUMMY_SP,
+                    SyntaxContext::empty().apply_mark(Mark::new()),
+                )
+            })
+            .clone();
+
+        let message = self.normalize_message(
+            StaticString {
+                value: message.into(),
+                constants: Vec::new(),
+            },
+            element.span,
+        );
+        self.check_message_syntax(&message, None, element.span);
+        let key = self.add_message(
+            &message,
+            explicit_id,
+            None,
+            MessageDetails {
+                description,
+                context,
+                previous_message: None,
+            },
+            element.span,
+        );
+
+        let mut args = vec![
+            Lit::Str(key.into()).as_arg(),
+            if values.is_empty() {
+                Expr::undefined(DUMMY_SP).as_arg()
+            } else {
+                ObjectLit {
+                    span: DUMMY_SP,
+                    props: values,
+                }
+                .as_arg()
+            },
+        ];
+        if self.config.is_development {
+            args.push(Expr::undefined(DUMMY_SP).as_arg());
+            args.push(Lit::Str(message.value.into()).as_arg());
+        }
+
+        Some(
+            CallExpr {
+                span: element.span,
+                callee: translator
+                    .make_member(IdentName::new("rich".into(), DUMMY_SP))
+                    .as_callee(),
+                args,
+                ..Default::default()
+            }
+            .into(),
+        )
     }
-}
 
-fn warn_dynamic_expression(expr: &Expr) {
-    HANDLER.with(|handler| {
-        handler
-            .struct_span_err(
-                expr.span(),
-                "Cannot extract message from dynamic expression, messages need to be statically \
-                 analyzable. If you need to provide runtime values, pass them as a separate \
-                 argument.",
-            )
-            .emit();
-    })
+    /// Declares the translator for `<Extracted>` elements if the outermost
+    /// function contains any, e.g. `const t$1 = useTranslations$1();`.
+    fn component_translator_decl(&mut self, component: ComponentScope) -> Option<Stmt> {
+        let translator = component.translator?;
+        let hook_type = if component.is_async {
+            HookType::GetTranslation
+        } else {
+            HookType::UseTranslation
+        };
+        let index = self.hook_import(
+            hook_type,
+            hook_type.default_target().into(),
+            SyntaxContext::empty(),
+        );
+
+        let mut init: Expr = CallExpr {
+            callee: self.hook_imports[index].local.clone().as_callee(),
+            ..Default::default()
+        }
+        .into();
+        if component.is_async {
+            init = AwaitExpr {
+                span: DUMMY_SP,
+                arg: Box::new(init),
+            }
+            .into();
+        }
+
+        Some(
+            VarDecl {
+                kind: VarDeclKind::Const,
+                decls: vec![VarDeclarator {
+                    span: DUMMY_SP,
+                    name: translator.into(),
+                    init: Some(Box::new(init)),
+                    definite: false,
+                }],
+                ..Default::default()
+            }
+            .into(),
+        )
+    }
+
+    /// Compiles `msg('Pending')` and `defineMessages({pending: 'Pending'})` to
+    /// message descriptors.
+    fn compile_marker_call(&mut self, call: &CallExpr) -> Option<Expr> {
+        let Callee::Expr(box Expr::Ident(callee)) = &call.callee else {
+            return None;
+        };
+        let marker = *self.message_markers.get(&callee.to_id())?;
+        let arg0 = call.args.first().map(|arg| &*arg.expr);
+
+        match (marker, arg0) {
+            (MessageMarker::Msg, Some(arg0)) => {
+                let Some(message) = self.constants.eval(arg0) else {
+                    warn_dynamic_expression(arg0);
+                    return None;
+                };
+                Some(self.message_descriptor(message, arg0, call.span))
+            }
+
+            (MessageMarker::DefineMessages, Some(Expr::Object(object))) => {
+                let mut props = Vec::with_capacity(object.props.len());
+                for prop in &object.props {
+                    let PropOrSpread::Prop(box Prop::KeyValue(KeyValueProp { key, value })) = prop
+                    else {
+                        emit_error(
+                            prop.span(),
+                            "Messages passed to `defineMessages` need to be defined as \
+                             `key: 'message'`.",
+                        );
+                        continue;
+                    };
+                    let Some(message) = self.constants.eval(value) else {
+                        warn_dynamic_expression(value);
+                        continue;
+                    };
+
+                    let descriptor = self.message_descriptor(message, value, value.span());
+                    props.push(PropOrSpread::Prop(Box::new(Prop::KeyValue(KeyValueProp {
+                        key: key.clone(),
+                        value: Box::new(descriptor),
+                    }))));
+                }
+
+                Some(Expr::Object(ObjectLit {
+                    span: object.span,
+                    props,
+                }))
+            }
+
+            _ => {
+                emit_error(
+                    call.span,
+                    &format!(
+                        "`{}` needs to receive {}.",
+                        callee.sym,
+                        match marker {
+                            MessageMarker::Msg => "a message",
+                            MessageMarker::DefineMessages => "an object literal of messages",
+                        }
+                    ),
+                );
+                None
+            }
+        }
+    }
+
+    /// Canonicalizes a message before its key is generated. Lone surrogates,
+    /// e.g. from `'\uD83D'`, can't be represented in the extracted catalogs.
+    fn normalize_message(&self, message: StaticString, span: Span) -> StaticString {
+        if message.value.as_atom().is_none() {
+            emit_error(
+                span,
+                "Messages can't contain lone surrogates like `\\uD83D`, as they can't be \
+                 represented as UTF-8. Use the complete surrogate pair or the character \
+                 itself instead.",
+            );
+        }
+
+        StaticString {
+            value: self
+                .config
+                .normalize
+                .apply(&message.value.to_string_lossy())
+                .into(),
+            constants: message.constants,
+        }
+    }
+
+    /// Reports ICU syntax errors at the affected characters of the literal
+    /// that a message was read from.
+    fn check_message_syntax(&self, message: &StaticString, node: Option<&Expr>, span: Span) {
+        let text = message.value.to_string_lossy();
+        if let Err(error) = parse_message(&text) {
+            emit_error(
+                message_text_span(node, &text, error.start, error.end).unwrap_or(span),
+                &format!("Invalid message: {}", error.message),
+            );
+        }
+    }
+
+    /// Records a message defined outside of a component and creates a
+    /// descriptor that can be passed to `t`.
+    fn message_descriptor(&mut self, message: StaticString, node: &Expr, span: Span) -> Expr {
+        let message = self.normalize_message(message, span);
+        self.check_message_syntax(&message, Some(node), span);
+        let key = self.add_message(&message, None, None, Default::default(), span);
+
+        let mut props = vec![PropOrSpread::Prop(Box::new(Prop::KeyValue(KeyValueProp {
+            key: PropName::Ident("id".into()),
+            value: Str::from(key).into(),
+        })))];
+
+        // The message is only used as a fallback in development
+        if self.config.is_development {
+            props.push(PropOrSpread::Prop(Box::new(Prop::KeyValue(KeyValueProp {
+                key: PropName::Ident("message".into()),
+                value: Str::from(message.value.clone()).into(),
+            }))));
+        }
+
+        Expr::Object(ObjectLit {
+            span: DUMMY_SP,
+            props,
+        })
+    }
+
+    /// Rewrites `t(descriptor)` to `t(descriptor.id)`.
+    fn rewrite_descriptor_call(
+        &mut self,
+        args: &mut Vec<ExprOrSpread>,
+        is_has_call: bool,
+        has_namespace: bool,
+    ) {
+        let descriptor = args[0].expr.clone();
+
+        if has_namespace {
+            emit_error(
+                descriptor.span(),
+                "Message descriptors can only be passed to a translator without a namespace.",
+            );
+            return;
+        }
+
+        args[0].expr = descriptor.clone().make_member("id".into()).into();
+
+        if self.config.is_development && !is_has_call {
+            while args.len() < 3 {
+                args.push(Expr::undefined(DUMMY_SP).as_arg());
+            }
+
+            args.push(descriptor.make_member("message".into()).as_arg());
+        }
+    }
+
+    /// Extracts the messages of a message argument, which is either a static
+    /// string or a conditional whose branches are all static strings.
+    fn extract_static_messages(&self, value: &Expr) -> Option<Vec<StaticString>> {
+        match unwrap_expr(value) {
+            Expr::Cond(CondExpr { cons, alt, .. }) => {
+                let mut messages = self.extract_static_messages(cons)?;
+                messages.extend(self.extract_static_messages(alt)?);
+                Some(messages)
+            }
+            _ => self.constants.eval(value).map(|message| {
+                let message = self.normalize_message(message, value.span());
+                self.check_message_syntax(&message, Some(value), value.span());
+                vec![message]
+            }),
+        }
+    }
+
+    /// Rewrites hooks destructured from `require(source)` or `import(source)`
+    /// to the translation hooks of the same module.
+    fn rewrite_hook_object_pat(&mut self, pat: &mut ObjectPat, source: Wtf8Atom) {
+        for prop in &mut pat.props {
+            let (name, local) = match prop {
+                ObjectPatProp::Assign(AssignPatProp {
+                    key, value: None, ..
+                }) => (key.sym.clone(), key.id.clone()),
+                ObjectPatProp::KeyValue(KeyValuePatProp {
+                    key: PropName::Ident(key),
+                    value: box Pat::Ident(local),
+                }) => (key.sym.clone(), local.id.clone()),
+                _ => continue,
+            };
+
+            let Some((hook_type, target)) = self.resolve_hook(&source, &name) else {
+                continue;
+            };
+            if target != source {
+                emit_error(
+                    prop.span(),
+                    &format!(
+                        "`{name}` is compiled to a hook from `{}`, which can only be \
+                         imported with an import declaration.",
+                        target.to_string_lossy()
+                    ),
+                );
+                continue;
+            }
+
+            // Declared in the scope of the destructuring, not as an import
+            let local_name = self.hook_local_name(hook_type, &target);
+            self.hook_local_names
+                .insert(local.to_id(), local_name.clone());
+            if !is_builtin_hook(&source, &name) {
+                self.custom_hooks.insert(local.to_id());
+            }
+
+            *prop = ObjectPatProp::KeyValue(KeyValuePatProp {
+                key: PropName::Ident(IdentName::new(hook_type.target_name().into(), DUMMY_SP)),
+                value: Box::new(Pat::Ident(
+                    Ident::new(local_name, DUMMY_SP, local.ctxt).into(),
+                )),
+            });
+        }
+    }
+
+    /// Rewrites the callee of a `useExtracted`/`getExtracted` call to the
+    /// translation hook and returns the translator it creates.
+    fn rewrite_hook_call(&mut self, call: &mut CallExpr) -> Option<TranslatorInfo> {
+        let is_builtin = match &mut call.callee {
+            Callee::Expr(box Expr::Ident(callee)) => {
+                let local = self.hook_local_names.get(&callee.to_id())?;
+                let is_builtin = !self.custom_hooks.contains(&callee.to_id());
+                *callee = Ident::new(local.clone(), DUMMY_SP, callee.ctxt);
+                is_builtin
+            }
+
+            // Handle namespace imports: intl.useExtracted()
+            Callee::Expr(callee @ box Expr::Member(_)) => {
+                let Expr::Member(MemberExpr {
+                    obj: box Expr::Ident(obj),
+                    prop: MemberProp::Ident(prop),
+                    ..
+                }) = &mut **callee
+                else {
+                    return None;
+                };
+                let source = self.hook_namespace_imports.get(&obj.to_id())?.clone();
+                let (hook_type, target) = self.resolve_hook(&source, &prop.sym)?;
+                let is_builtin = is_builtin_hook(&source, &prop.sym);
+
+                if target == source {
+                    prop.sym = hook_type.target_name().into();
+                } else {
+                    let index = self.hook_import(hook_type, target, obj.ctxt);
+                    **callee = self.hook_imports[index].local.clone().into();
+                }
+                is_builtin
+            }
+
+            _ => return None,
+        };
+
+        // Either `useExtracted('Checkout')` or `getExtracted({locale, namespace: 'Checkout'})`
+        let namespace = call.args.first().and_then(|arg| {
+            let value = match unwrap_expr(&arg.expr) {
+                // `useExtracted(undefined)`
+                Expr::Ident(ident) if &*ident.sym == "undefined" => return None,
+                Expr::Unary(UnaryExpr {
+                    op: UnaryOp::Void, ..
+                }) => return None,
+                Expr::Object(ObjectLit { props, .. }) => {
+                    props.iter().find_map(|prop| match prop.as_prop()? {
+                        box Prop::KeyValue(KeyValueProp { key, value })
+                            if prop_name_is(key, "namespace") =>
+                        {
+                            Some((**value).clone())
+                        }
+                        box Prop::Shorthand(ident) if ident.sym == "namespace" => {
+                            Some(ident.clone().into())
+                        }
+                        _ => None,
+                    })?
+                }
+                value => value.clone(),
+            };
+
+            // Custom hooks could receive other arguments
+            let namespace = self.constants.eval(&value);
+            if namespace.is_none() && is_builtin {
+                emit_error(
+                    value.span(),
+                    "The namespace needs to be statically analyzable, e.g. a string literal or \
+                     a `const` declared at the top level of the module.",
+                );
+            }
+            namespace.map(|namespace| namespace.value)
+        });
+
+        Some(TranslatorInfo {
+            namespace,
+            method: None,
+        })
+    }
+
+    /// Returns the name of the extracted hook that a call refers to, e.g.
+    /// `getExtracted` for `intl.getExtracted()`.
+    fn hook_call_name<'a>(&self, call: &'a CallExpr) -> Option<&'a Atom> {
+        match &call.callee {
+            Callee::Expr(box Expr::Ident(callee)) => self
+                .hook_local_names
+                .contains_key(&callee.to_id())
+                .then_some(&callee.sym),
+            Callee::Expr(box Expr::Member(MemberExpr {
+                obj: box Expr::Ident(obj),
+                prop: MemberProp::Ident(prop),
+                ..
+            })) => {
+                let source = self.hook_namespace_imports.get(&obj.to_id())?;
+                self.resolve_hook(source, &prop.sym).map(|_| &prop.sym)
+            }
+            _ => None,
+        }
+    }
+
+    /// Rewrites a hook that a function returns with its last statement and
+    /// records its translator, e.g. `return getExtracted()` or
+    /// `() => useExtracted()`.
+    fn define_returned_hook(&mut self, name: Id, value: Option<&mut Expr>) {
+        let Some(
+            Expr::Call(call)
+            | Expr::Await(AwaitExpr {
+                arg: box Expr::Call(call),
+                ..
+            }),
+        ) = value.map(unwrap_expr_mut)
+        else {
+            return;
+        };
+
+        if let Some(translator) = self.rewrite_hook_call(call) {
+            self.returned_translators
+                .insert(name, ReturnedTranslator::Translator(translator));
+        }
+    }
+
+    /// Binds the parameter of a promise callback to the translator that the
+    /// hook resolves to, e.g. `getExtracted().then((t) => ...)`.
+    fn define_then_callback_param(&mut self, call: &mut CallExpr) {
+        let Callee::Expr(box Expr::Member(MemberExpr {
+            obj,
+            prop: MemberProp::Ident(prop),
+            ..
+        })) = &mut call.callee
+        else {
+            return;
+        };
+        if prop.sym != "then" {
+            return;
+        }
+        let Expr::Call(hook_call) = unwrap_expr_mut(obj) else {
+            return;
+        };
+        let Some(translator) = self.rewrite_hook_call(hook_call) else {
+            return;
+        };
+
+        let param = call
+            .args
+            .first()
+            .and_then(|arg| match unwrap_expr(&arg.expr) {
+                Expr::Arrow(arrow) => arrow.params.first(),
+                Expr::Fn(fn_expr) => fn_expr.function.params.first().map(|param| &param.pat),
+                _ => None,
+            });
+        if let Some(Pat::Ident(param)) = param {
+            self.define_translator(param.to_id(), translator);
+        }
+    }
+
+    /// Binds the parameters of a same-module helper to the translators that
+    /// are passed to it, e.g. `renderRow(t, item)`.
+    fn define_helper_params(&mut self, call: &CallExpr) {
+        let Callee::Expr(box Expr::Ident(callee)) = &call.callee else {
+            return;
+        };
+        let Some(params) = self.helpers.params.get(&callee.to_id()) else {
+            return;
+        };
+
+        let mut bindings = Vec::new();
+        let mut values = Vec::new();
+        for (index, param) in params.iter().enumerate() {
+            let Some(param) = param else {
+                continue;
+            };
+            let arg = call.args.get(index);
+            let translator = match arg {
+                Some(ExprOrSpread {
+                    spread: None,
+                    expr: box Expr::Ident(arg_ident),
+                }) => self.translator_map.get(&arg_ident.to_id()),
+                _ => None,
+            };
+            let span = arg.map_or(call.span, |arg| arg.expr.span());
+
+            values.push((param.clone(), translator.is_some(), span));
+            if let Some(translator) = translator {
+                bindings.push((param.clone(), translator.clone(), span));
+            }
+        }
+
+        // Messages in the helper are compiled to keys for all callers
+        for (param, is_translator, span) in values {
+            match self.helper_param_values.get(&param) {
+                Some(&previous) if previous != is_translator => {
+                    emit_error(
+                        span,
+                        &format!(
+                            "The parameter `{}` of `{}` receives a translator at one call site \
+                             and another value at another one. Messages in a helper function \
+                             are compiled to keys, therefore the parameter needs to receive a \
+                             translator at every call site.",
+                            param.0, callee.sym
+                        ),
+                    );
+                }
+                Some(_) => {}
+                None => {
+                    self.helper_param_values.insert(param, is_translator);
+                }
+            }
+        }
+
+        for (param, translator, span) in bindings {
+            let existing = self.translator_map.get(&param);
+            if can_bind_translator(existing, &translator, span, || {
+                format!(
+                    "Translator passed to `{}` with a different namespace than at another call \
+                     site. Messages in a helper function can only be extracted for a single \
+                     namespace.",
+                    callee.sym
+                )
+            }) {
+                self.define_translator(param, translator);
+            }
+        }
+    }
+
+    /// Records the state of the scope a helper is declared in, to restore it
+    /// once the helper is visited.
+    fn defer_helper(&mut self, id: Id, is_arrow: bool) {
+        let context = HelperContext {
+            is_nested: self.component.is_some(),
+            // Other functions have their own `this`
+            this_translators: if is_arrow {
+                self.this_translators.last().cloned()
+            } else {
+                None
+            },
+        };
+        self.helper_contexts.insert(id, context);
+    }
+
+    /// Visits a deferred helper within the state of the scope it is declared in.
+    fn visit_deferred_helper<N>(&mut self, id: &Id, node: &mut N)
+    where
+        N: VisitMutWith<Self>,
+    {
+        let context = self.helper_contexts.remove(id).unwrap_or_default();
+        if context.is_nested {
+            // Translators for `<Extracted>` can't be declared in a nested function
+            self.component = Some(ComponentScope {
+                is_async: false,
+                is_component: false,
+                translator: None,
+            });
+        }
+        let has_this_translators = context.this_translators.is_some();
+        if let Some(this_translators) = context.this_translators {
+            self.this_translators.push(this_translators);
+        }
+
+        node.visit_mut_with(self);
+
+        if has_this_translators {
+            self.this_translators.pop();
+        }
+        self.component = None;
+    }
+
+    /// Visits the bodies of helpers that were skipped during the main pass.
+    /// Helpers that already received a translator are visited first, so
+    /// translators they pass on to other helpers are known in turn.
+    fn visit_deferred_helpers<N>(&mut self, node: &mut N)
+    where
+        N: for<'a> VisitMutWith<DeferredHelperVisitor<'a>>,
+    {
+        let mut pending = self.helpers.order.clone();
+
+        while !pending.is_empty() {
+            let mut ready: FxHashSet<Id> = pending
+                .iter()
+                .filter(|id| {
+                    self.helpers.params[*id]
+                        .iter()
+                        .flatten()
+                        .any(|param| self.translator_map.contains_key(param))
+                })
+                .cloned()
+                .collect();
+            if ready.is_empty() {
+                ready.insert(pending[0].clone());
+            }
+            pending.retain(|id| !ready.contains(id));
+
+            node.visit_mut_with(&mut DeferredHelperVisitor {
+                visitor: self,
+                helpers: &ready,
+            });
+        }
+    }
+}
+
+/// Locates the declarations of deferred helpers and runs the transform on
+/// their bodies.
+struct DeferredHelperVisitor<'a> {
+    visitor: &'a mut TransformVisitor,
+    helpers: &'a FxHashSet<Id>,
+}
+
+impl VisitMut for DeferredHelperVisitor<'_> {
+    fn visit_mut_fn_decl(&mut self, node: &mut FnDecl) {
+        if self.helpers.contains(&node.ident.to_id()) {
+            self.visitor
+                .visit_deferred_helper(&node.ident.to_id(), &mut node.function);
+        } else {
+            node.visit_mut_children_with(self);
+        }
+    }
+
+    fn visit_mut_var_declarator(&mut self, node: &mut VarDeclarator) {
+        match (node.name.as_ident(), &mut node.init) {
+            (Some(name), Some(init)) if self.helpers.contains(&name.to_id()) => {
+                self.visitor.visit_deferred_helper(&name.to_id(), init);
+            }
+            _ => node.visit_mut_children_with(self),
+        }
+    }
+}
+
+struct HookImport {
+    hook_type: HookType,
+    target: Wtf8Atom,
+    local: Ident,
+
+    /// Whether an existing import declaration was rewritten to this import,
+    /// otherwise one is added to the module.
+    is_declared: bool,
+}
+
+#[derive(Default)]
+struct HelperContext {
+    /// Whether the helper is declared within another function, which is the
+    /// one that could declare a translator for `<Extracted>` elements.
+    is_nested: bool,
+
+    /// Translators assigned to `this` that an arrow function inherits.
+    this_translators: Option<FxHashMap<Atom, TranslatorInfo>>,
+}
+
+struct ComponentScope {
+    is_async: bool,
+
+    /// Whether the function is a component or hook, which can call
+    /// `useTranslations`.
+    is_component: bool,
+
+    /// Declared at the start of the function body once an `<Extracted>`
+    /// element is found.
+    translator: Option<Ident>,
+}
+
+#[derive(Debug, Clone)]
+struct TranslatorInfo {
+    namespace: Option<Wtf8Atom>,
+
+    /// The method a binding refers to, e.g. `rich` in `const {rich} = t`
+    method: Option<Atom>,
+}
+
+/// What a function of the module returns, e.g. a custom hook.
+enum ReturnedTranslator {
+    Translator(TranslatorInfo),
+    Props(FxHashMap<Atom, TranslatorInfo>),
+}
+
+/// Information about a message that isn't part of its text.
+#[derive(Default)]
+struct MessageDetails {
+    description: Option<Wtf8Atom>,
+    context: Option<Wtf8Atom>,
+    previous_message: Option<Wtf8Atom>,
+}
+
+#[derive(Debug, Clone, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct SourceMessage {
+    pub id: Wtf8Atom,
+    pub message: Wtf8Atom,
+    pub description: Option<Wtf8Atom>,
+    /// Distinguishes the same text with different meanings, e.g. `file` for
+    /// `Open`, like a `msgctxt` in gettext
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub context: Option<Wtf8Atom>,
+    /// The module-scope constants the message was composed of
+    #[serde(skip_serializing_if = "Vec::is_empty")]
+    pub constants: Vec<Atom>,
+    /// The text before a change that kept the key via `/* keep: ... */`
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub previous_message: Option<Wtf8Atom>,
+    pub reference: Reference,
+}
+
+#[derive(Debug, Clone, Serialize)]
+pub struct Reference {
+    pub path: String,
+    pub line: usize,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Deserialize)]
+pub enum HookType {
+    #[serde(rename = "useTranslations")]
+    UseTranslation,
+    #[serde(rename = "getTranslations")]
+    GetTranslation,
+}
+
+impl HookType {
+    /// The hook exported by a module specifier (e.g. `next-intl/server`)
+    fn from_source(source: &[u8]) -> Option<Self> {
+        match source {
+            b"next-intl" => Some(HookType::UseTranslation),
+            b"next-intl/server" => Some(HookType::GetTranslation),
+            _ => None,
+        }
+    }
+
+    /// The module the real hook is imported from by default
+    fn default_target(self) -> &'static str {
+        match self {
+            HookType::UseTranslation => "next-intl",
+            HookType::GetTranslation => "next-intl/server",
+        }
+    }
+
+    /// The extracted hook name we look for in imports (e.g. `useExtracted`)
+    fn extracted_name(self) -> &'static str {
+        match self {
+            HookType::UseTranslation => "useExtracted",
+            HookType::GetTranslation => "getExtracted",
+        }
+    }
+
+    /// The real hook name we import from next-intl (e.g. `useTranslations`)
+    fn target_name(self) -> &'static str {
+        match self {
+            HookType::UseTranslation => "useTranslations",
+            HookType::GetTranslation => "getTranslations",
+        }
+    }
+}
+
+impl VisitMut for TransformVisitor {
+    fn visit_mut_call_expr(&mut self, call: &mut CallExpr) {
+        self.define_helper_params(call);
+        self.define_then_callback_param(call);
+
+        // Hooks in positions where the translator isn't tracked, e.g. `(await getExtracted())('Title')`
+        if let Some(name) = self.hook_call_name(call) {
+            emit_error(
+                call.span,
+                &format!(
+                    "The translator returned by `{name}` can't be tracked here. Assign it to a \
+                     variable (e.g. `const t = {name}()`), use `{name}().then((t) => ...)` or \
+                     return it from a function of the module.",
+                ),
+            );
+            self.rewrite_hook_call(call);
+        }
+
+        if let Callee::Expr(callee) = &call.callee {
+            self.transform_translator_call(callee, &mut call.args, call.span);
+        }
+
+        call.visit_mut_children_with(self);
+    }
+
+    // Handle comments in JSX: {/* i18n: ... */}
+    fn visit_mut_jsx_element_childs(&mut self, children: &mut Vec<JSXElementChild>) {
+        let prev = self.pending_description.take();
+        for child in children {
+            match child {
+                JSXElementChild::JSXExprContainer(JSXExprContainer {
+                    span,
+                    expr: JSXExpr::JSXEmptyExpr(_),
+                }) => {
+                    // Attached to the opening brace
+                    self.pending_description = self.comment_description(span.lo + BytePos(1), true);
+                }
+                JSXElementChild::JSXText(text) if text.value.trim().is_empty() => {}
+                child => {
+                    child.visit_mut_with(self);
+                    self.pending_description = None;
+                }
+            }
+        }
+        self.pending_description = prev;
+    }
+
+    fn visit_mut_stmt(&mut self, stmt: &mut Stmt) {
+        // Only applies if the statement is a translator call itself
+        let description = if self.is_translator_call_stmt(stmt) {
+            self.comment_description(stmt.span().lo, false)
+        } else {
+            None
+        };
+        let prev = std::mem::replace(&mut self.pending_description, description);
+        stmt.visit_mut_children_with(self);
+        self.pending_description = prev;
+    }
+
+    // Handle optional calls: t?.('message')
+    fn visit_mut_opt_call(&mut self, call: &mut OptCall) {
+        self.transform_translator_call(&call.callee, &mut call.args, call.span);
+
+        call.visit_mut_children_with(self);
+    }
+
+    fn visit_mut_expr(&mut self, expr: &mut Expr) {
+        if let Expr::Call(call) = expr {
+            if let Some(descriptor) = self.compile_marker_call(call) {
+                *expr = descriptor;
+                return;
+            }
+        }
+
+        if let Expr::JSXElement(element) = expr {
+            if let Some(call) = self.compile_extracted_element(element) {
+                *expr = call;
+            }
+        }
+
+        expr.visit_mut_children_with(self);
+    }
+
+    fn visit_mut_jsx_element_child(&mut self, child: &mut JSXElementChild) {
+        if let JSXElementChild::JSXElement(element) = child {
+            if let Some(call) = self.compile_extracted_element(element) {
+                *child = JSXElementChild::JSXExprContainer(JSXExprContainer {
+                    span: element.span,
+                    expr: JSXExpr::Expr(Box::new(call)),
+                });
+            }
+        }
+
+        child.visit_mut_children_with(self);
+    }
+
+    fn visit_mut_function(&mut self, node: &mut Function) {
+        // Descriptions of the enclosing statement don't apply to the body
+        let pending_description = self.pending_description.take();
+
+        if self.component.is_some() {
+            node.visit_mut_children_with(self);
+            self.pending_description = pending_description;
+            return;
+        }
+
+        self.component = Some(ComponentScope {
+            is_async: node.is_async,
+            is_component: self.component_hint == Some(node.span) && !node.span.is_dummy(),
+            translator: None,
+        });
+        node.visit_mut_children_with(self);
+        self.pending_description = pending_description;
+
+        let component = self.component.take().unwrap();
+        if let (Some(decl), Some(body)) =
+            (self.component_translator_decl(component), &mut node.body)
+        {
+            prepend_stmts(&mut body.stmts, std::iter::once(decl));
+        }
+    }
+
+    fn visit_mut_arrow_expr(&mut self, node: &mut ArrowExpr) {
+        // Descriptions of the enclosing statement don't apply to the body
+        let pending_description = self.pending_description.take();
+
+        if self.component.is_some() {
+            node.visit_mut_children_with(self);
+            self.pending_description = pending_description;
+            return;
+        }
+
+        self.component = Some(ComponentScope {
+            is_async: node.is_async,
+            is_component: self.component_hint == Some(node.span) && !node.span.is_dummy(),
+            translator: None,
+        });
+        node.visit_mut_children_with(self);
+        self.pending_description = pending_description;
+
+        let component = self.component.take().unwrap();
+        if let Some(decl) = self.component_translator_decl(component) {
+            // () => <Extracted>...</Extracted> needs a block for the declaration
+            if let BlockStmtOrExpr::Expr(expr) = &mut *node.body {
+                let stmt = ReturnStmt {
+                    span: DUMMY_SP,
+                    arg: Some(expr.take()),
+                }
+                .into();
+                *node.body = BlockStmtOrExpr::BlockStmt(BlockStmt {
+                    stmts: vec![stmt],
+                    ..Default::default()
+                });
+            }
+            if let BlockStmtOrExpr::BlockStmt(body) = &mut *node.body {
+                prepend_stmts(&mut body.stmts, std::iter::once(decl));
+            }
+        }
+    }
+
+    fn visit_mut_program(&mut self, program: &mut Program) {
+        for error in self.config_errors.drain(..) {
+            HANDLER.with(|handler| handler.struct_err(&error).emit());
+        }
+
+        program.visit_mut_children_with(self);
+    }
+
+    fn visit_mut_module(&mut self, module: &mut Module) {
+        module.body.retain_mut(|import| {
+            if let ModuleItem::ModuleDecl(ModuleDecl::Import(import)) = import {
+                let source = import.src.value.clone();
+                let has_specifiers = !import.specifiers.is_empty();
+
+                import.specifiers.retain_mut(|specifier| match specifier {
+                    ImportSpecifier::Named(named_spec) => {
+                        let orig_name = named_spec
+                            .imported
+                            .as_ref()
+                            .and_then(|x| match x {
+                                ModuleExportName::Ident(ident) => Some(ident.sym.clone()),
+                                ModuleExportName::Str(..) => None,
+                            })
+                            .unwrap_or_else(|| named_spec.local.sym.clone())
+                            .clone();
+
+                        if source == "next-intl" {
+                            if let Some(marker) = MessageMarker::from_name(&orig_name) {
+                                // Calls are compiled to descriptors
+                                self.message_markers
+                                    .insert(named_spec.local.to_id(), marker);
+                                return false;
+                            }
+
+                            if orig_name == "Extracted" {
+                                // Elements are compiled to `t.rich` calls
+                                self.extracted_components.insert(named_spec.local.to_id());
+                                return false;
+                            }
+                        }
+
+                        let Some((hook_type, target)) = self.resolve_hook(&source, &orig_name)
+                        else {
+                            return true;
+                        };
+
+                        let is_in_place = target == source;
+                        let index = self.hook_import(hook_type, target, named_spec.local.ctxt);
+                        self.hook_local_names.insert(
+                            named_spec.local.to_id(),
+                            self.hook_imports[index].local.sym.clone(),
+                        );
+                        if !is_builtin_hook(&source, &orig_name) {
+                            self.custom_hooks.insert(named_spec.local.to_id());
+                        }
+
+                        if !is_in_place || self.hook_imports[index].is_declared {
+                            // Declared by another import or added to the module later
+                            return false;
+                        }
+
+                        self.hook_imports[index].is_declared = true;
+                        named_spec.imported =
+                            Some(ModuleExportName::Ident(hook_type.target_name().into()));
+                        named_spec.local = self.hook_imports[index].local.clone();
+                        true
+                    }
+
+                    // Hooks are accessed as members: intl.useExtracted()
+                    ImportSpecifier::Namespace(ImportStarAsSpecifier { local, .. })
+                    | ImportSpecifier::Default(ImportDefaultSpecifier { local, .. }) => {
+                        if self.is_hook_source(&source) {
+                            self.hook_namespace_imports
+                                .insert(local.to_id(), source.clone());
+                        }
+                        true
+                    }
+                });
+
+                // Only compile-time APIs like `msg` were imported
+                if has_specifiers && import.specifiers.is_empty() && source == "next-intl" {
+                    return false;
+                }
+            }
+            true
+        });
+
+        self.constants = StaticEvaluator::collect_module(module);
+        self.descriptor_bindings = collect_descriptor_bindings(module, &self.message_markers);
+        self.helpers = HelperFunctions::collect(
+            module,
+            self.hook_local_names
+                .keys()
+                .chain(self.hook_namespace_imports.keys())
+                .cloned(),
+            &|source, name| self.resolve_hook(source, name).is_some(),
+        );
+        module.visit_mut_children_with(self);
+        self.visit_deferred_helpers(module);
+        check_hook_references(module, &self.hook_local_names, &self.message_markers);
+
+        // Add imports for hooks that are compiled to a different module
+        let imports = self
+            .hook_imports
+            .iter()
+            .filter(|import| !import.is_declared)
+            .map(|import| {
+                ModuleItem::ModuleDecl(ModuleDecl::Import(ImportDecl {
+                    span: DUMMY_SP,
+                    specifiers: vec![ImportSpecifier::Named(ImportNamedSpecifier {
+                        span: DUMMY_SP,
+                        local: import.local.clone(),
+                        imported: Some(ModuleExportName::Ident(
+                            import.hook_type.target_name().into(),
+                        )),
+                        is_type_only: false,
+                    })],
+                    src: Box::new(Str {
+                        span: DUMMY_SP,
+                        value: import.target.clone(),
+                        raw: None,
+                    }),
+                    type_only: false,
+                    with: None,
+                    phase: Default::default(),
+                }))
+            })
+            .collect::<Vec<_>>();
+
+        // After directives like `'use client'`, which otherwise lose their effect
+        prepend_stmts(&mut module.body, imports.into_iter());
+    }
+
+    fn visit_mut_script(&mut self, script: &mut Script) {
+        self.constants = StaticEvaluator::collect_script(script);
+        self.helpers = HelperFunctions::collect(script, [], &|source, name| {
+            self.resolve_hook(source, name).is_some()
+        });
+        script.visit_mut_children_with(self);
+        self.visit_deferred_helpers(script);
+        check_hook_references(script, &self.hook_local_names, &self.message_markers);
+    }
+
+    fn visit_mut_assign_expr(&mut self, node: &mut AssignExpr) {
+        // Handle assignments: t = useExtracted(); or this.t = t;
+        if node.op == AssignOp::Assign {
+            let target = match &node.left {
+                AssignTarget::Simple(SimpleAssignTarget::Ident(name)) => {
+                    Some((Some(name.to_id()), name.sym.clone()))
+                }
+                AssignTarget::Simple(SimpleAssignTarget::Member(MemberExpr {
+                    obj: box Expr::This(_),
+                    prop,
+                    ..
+                })) if !self.this_translators.is_empty() => {
+                    member_prop_name(prop).map(|prop| (None, prop))
+                }
+                _ => None,
+            };
+
+            if let Some((id, name)) = target {
+                if let Some(translator) = self.translator_from_value(&mut node.right) {
+                    let existing = match &id {
+                        Some(id) => self.translator_map.get(id),
+                        None => self.this_translators.last().unwrap().get(&name),
+                    };
+                    let can_bind = can_bind_translator(existing, &translator, node.span, || {
+                        format!(
+                            "`{}{name}` is assigned translators with different namespaces. Use a \
+                             separate variable for each namespace.",
+                            if id.is_some() { "" } else { "this." }
+                        )
+                    });
+                    if can_bind {
+                        match id {
+                            Some(id) => self.define_translator(id, translator),
+                            None => {
+                                self.this_translators
+                                    .last_mut()
+                                    .unwrap()
+                                    .insert(name, translator);
+                            }
+                        }
+                    }
+                }
+            }
+        }
+
+        node.visit_mut_children_with(self);
+    }
+
+    fn visit_mut_class(&mut self, node: &mut Class) {
+        self.this_translators.push(Default::default());
+        node.visit_mut_children_with(self);
+        self.this_translators.pop();
+    }
+
+    // Functions have their own `this`
+    fn visit_mut_fn_expr(&mut self, node: &mut FnExpr) {
+        if self.this_translators.is_empty() {
+            node.visit_mut_children_with(self);
+            return;
+        }
+
+        self.this_translators.push(Default::default());
+        node.visit_mut_children_with(self);
+        self.this_translators.pop();
+    }
+
+    fn visit_mut_fn_decl(&mut self, node: &mut FnDecl) {
+        // Visited once translators passed to it are known
+        if self.helpers.params.contains_key(&node.ident.to_id()) {
+            self.defer_helper(node.ident.to_id(), false);
+            return;
+        }
+
+        if is_component_name(&node.ident.sym) {
+            self.component_hint = Some(node.function.span);
+        }
+
+        // Handle functions returning a hook: function loadT() { return getExtracted(); }
+        self.define_returned_hook(
+            node.ident.to_id(),
+            node.function.body.as_mut().and_then(last_return_value),
+        );
+
+        node.visit_mut_children_with(self);
+
+        // Handle custom hooks: function useLabels() { ...; return {t}; }
+        self.define_returned_translator(node.ident.to_id(), node.function.body.as_ref());
+    }
+
+    fn visit_mut_export_default_decl(&mut self, node: &mut ExportDefaultDecl) {
+        // Anonymous default exports are typically pages or layouts
+        if let DefaultDecl::Fn(FnExpr { ident, function }) = &node.decl {
+            if ident
+                .as_ref()
+                .map_or(true, |ident| is_component_name(&ident.sym))
+            {
+                self.component_hint = Some(function.span);
+            }
+        }
+
+        node.visit_mut_children_with(self);
+    }
+
+    fn visit_mut_export_default_expr(&mut self, node: &mut ExportDefaultExpr) {
+        if let Some(span) = component_fn_span(&node.expr) {
+            self.component_hint = Some(span);
+        }
+
+        node.visit_mut_children_with(self);
+    }
+
+    fn visit_mut_var_declarator(&mut self, node: &mut VarDeclarator) {
+        if let (Some(name), Some(init)) = (node.name.as_ident(), node.init.as_deref()) {
+            if (init.is_arrow() || init.is_fn_expr())
+                && self.helpers.params.contains_key(&name.to_id())
+            {
+                self.defer_helper(name.to_id(), init.is_arrow());
+                return;
+            }
+        }
+
+        if let Some(init) = &mut node.init {
+            match &node.name {
+                // Handle direct or awaited CallExpression: const t = useExtracted();
+                // and aliases: const translate = t;
+                Pat::Ident(name) => {
+                    if let Some(translator) = self.translator_from_value(init) {
+                        self.define_translator(name.to_id(), translator);
+                    } else if let Expr::Object(object) = unwrap_expr(init) {
+                        // Handle translator bags: const labels = {t, locale};
+                        self.define_translator_props(name.to_id(), object);
+                    } else if let Some(props) = self.resolve_translator_props(init).cloned() {
+                        // Handle bags returned by custom hooks: const labels = useLabels();
+                        self.translator_props.insert(name.to_id(), props);
+                    }
+                }
+
+                // Handle parallel calls: const [t, user] = await Promise.all([getExtracted(), ...]);
+                Pat::Array(ArrayPat { elems: names, .. }) => {
+                    if let Expr::Await(AwaitExpr {
+                        arg: box Expr::Call(init_call),
+                        ..
+                    }) = &mut **init
+                    {
+                        if is_promise_all(init_call) {
+                            if let Some(Expr::Array(ArrayLit { elems, .. })) =
+                                init_call.args.first_mut().map(|arg| &mut *arg.expr)
+                            {
+                                for (elem, name) in elems.iter_mut().zip(names) {
+                                    let Some(ExprOrSpread {
+                                        spread: None,
+                                        expr:
+                                            box (Expr::Call(elem_call)
+                                            | Expr::Await(AwaitExpr {
+                                                arg: box Expr::Call(elem_call),
+                                                ..
+                                            })),
+                                    }) = elem
+                                    else {
+                                        continue;
+                                    };
+
+                                    if let Some(translator) = self.rewrite_hook_call(elem_call) {
+                                        if let Some(Pat::Ident(name)) = name {
+                                            self.define_translator(name.to_id(), translator);
+                                        }
+                                    }
+                                }
+                            }
+                        }
+                    }
+                }
+
+                // Handle CommonJS and dynamic imports: const {useExtracted} = require('next-intl');
+                Pat::Object(_) => {
+                    let source = match &**init {
+                        Expr::Call(call)
+                        | Expr::Await(AwaitExpr {
+                            arg: box Expr::Call(call),
+                            ..
+                        }) => module_source(call),
+                        _ => None,
+                    };
+
+                    match (source, &mut node.name) {
+                        (Some(source), Pat::Object(pat)) => {
+                            self.rewrite_hook_object_pat(pat, source);
+                        }
+
+                        // Handle destructuring: const {rich} = t; or const {t} = labels;
+                        (None, Pat::Object(pat)) => self.define_destructured_translators(pat, init),
+                        _ => {}
+                    }
+                }
+
+                _ => {}
+            }
+        }
+
+        if let (Some(name), Some(init)) = (node.name.as_ident(), &node.init) {
+            if is_component_name(&name.sym) {
+                if let Some(span) = component_fn_span(init) {
+                    self.component_hint = Some(span);
+                }
+            }
+        }
+
+        // Handle functions returning a hook: const loadT = () => getExtracted();
+        if let (Some(name), Some(init)) = (node.name.as_ident(), node.init.as_deref_mut()) {
+            let value = match unwrap_expr_mut(init) {
+                Expr::Arrow(ArrowExpr { body, .. }) => match &mut **body {
+                    BlockStmtOrExpr::BlockStmt(body) => last_return_value(body),
+                    BlockStmtOrExpr::Expr(body) => Some(&mut **body),
+                },
+                Expr::Fn(FnExpr { function, .. }) => {
+                    function.body.as_mut().and_then(last_return_value)
+                }
+                _ => None,
+            };
+            self.define_returned_hook(name.to_id(), value);
+        }
+
+        node.visit_mut_children_with(self);
+
+        // Handle custom hooks: const useLabels = () => { ...; return {t}; };
+        if let (Some(name), Some(init)) = (node.name.as_ident(), node.init.as_deref()) {
+            let body = match unwrap_expr(init) {
+                Expr::Arrow(ArrowExpr {
+                    body: box BlockStmtOrExpr::BlockStmt(body),
+                    ..
+                }) => Some(body),
+                Expr::Fn(FnExpr { function, .. }) => function.body.as_ref(),
+                _ => None,
+            };
+            self.define_returned_translator(name.to_id(), body);
+        }
+    }
+}
+
+/// Properties of the object syntax, e.g. `t({id, message, description, values, formats})`
+const MESSAGE_OBJECT_KEYS: [&str; 6] = [
+    "id",
+    "message",
+    "description",
+    "context",
+    "values",
+    "formats",
+];
+
+/// Reads the properties of the object syntax like `{message: 'Hello!'}`,
+/// `{'message': 'Hello!'}` or `{message}` and reports the ones that can't be
+/// analyzed statically.
+fn message_object_props(object: &ObjectLit) -> Vec<(Atom, Box<Expr>)> {
+    let mut props: Vec<(Atom, Box<Expr>)> = Vec::with_capacity(object.props.len());
+    let mut has_errors = false;
+    for prop in &object.props {
+        let (key, key_span, value) = match prop {
+            PropOrSpread::Prop(box Prop::KeyValue(KeyValueProp { key, value })) => {
+                let name = match key {
+                    PropName::Ident(ident) => Some(ident.sym.clone()),
+                    PropName::Str(s) => s.value.as_atom().cloned(),
+                    _ => None,
+                };
+                let Some(name) = name else {
+                    emit_error(
+                        key.span(),
+                        "Keys of the message object need to be static, e.g. `message`.",
+                    );
+                    has_errors = true;
+                    continue;
+                };
+                (name, key.span(), value.clone())
+            }
+            PropOrSpread::Prop(box Prop::Shorthand(ident)) => (
+                ident.sym.clone(),
+                ident.span,
+                Box::new(ident.clone().into()),
+            ),
+            PropOrSpread::Spread(spread) => {
+                emit_error(
+                    spread.span(),
+                    "Spreading into the message object isn't supported, messages need to be \
+                     statically analyzable.",
+                );
+                has_errors = true;
+                continue;
+            }
+            PropOrSpread::Prop(prop) => {
+                emit_error(
+                    prop.span(),
+                    "Properties of the message object need to be defined as `key: value`.",
+                );
+                has_errors = true;
+                continue;
+            }
+        };
+
+        if !MESSAGE_OBJECT_KEYS.contains(&&*key) {
+            emit_error(
+                key_span,
+                &format!(
+                    "Unknown property `{key}` in the message object. Supported properties are \
+                     `id`, `message`, `description`, `context`, `values` and `formats`."
+                ),
+            );
+            has_errors = true;
+            continue;
+        }
+        if props.iter().any(|(existing, _)| *existing == key) {
+            emit_error(
+                key_span,
+                &format!("Duplicate property `{key}` in the message object."),
+            );
+            has_errors = true;
+            continue;
+        }
+
+        props.push((key, value));
+    }
+
+    // Malformed properties are reported already
+    if !has_errors && !props.iter().any(|(key, _)| key == "message") {
+        emit_error(
+            object.span,
+            "The message object needs to have a `message` property.",
+        );
+    }
+
+    props
+}
+
+/// Checks for a key like `namespace` or `'namespace'`
+fn prop_name_is(key: &PropName, name: &str) -> bool {
+    match key {
+        PropName::Ident(ident) => ident.sym == name,
+        PropName::Str(s) => s.value == name,
+        _ => false,
+    }
+}
+
+/// Reads the name of a property like `.rich` or `['rich']`
+fn member_prop_name(prop: &MemberProp) -> Option<Atom> {
+    match prop {
+        MemberProp::Ident(prop) => Some(prop.sym.clone()),
+        MemberProp::Computed(ComputedPropName {
+            expr: box Expr::Lit(Lit::Str(prop)),
+            ..
+        }) => prop.value.as_atom().cloned(),
+        _ => None,
+    }
+}
+
+/// Locates a range of a message within the literal it was read from. The
+/// whole literal is used if the text differs from the source, e.g. due to
+/// escape sequences or normalization.
+fn message_text_span(node: Option<&Expr>, text: &str, start: usize, end: usize) -> Option<Span> {
+    let node = unwrap_expr(node?);
+    let (lo, raw) = match node {
+        Expr::Lit(Lit::Str(Str {
+            span,
+            raw: Some(raw),
+            ..
+        })) => (span.lo + BytePos(1), raw.get(1..raw.len() - 1)),
+        Expr::Tpl(Tpl { quasis, exprs, .. }) if exprs.is_empty() && quasis.len() == 1 => {
+            (quasis[0].span.lo, Some(&*quasis[0].raw))
+        }
+        _ => return Some(node.span()),
+    };
+    if raw != Some(text) {
+        return Some(node.span());
+    }
+
+    // Errors at the end of the text point at the closing quote
+    let end = end.max(start + 1);
+    Some(Span::new(
+        lo + BytePos(start as u32),
+        lo + BytePos(end as u32),
+    ))
+}
+
+/// The key of a message including its namespace, e.g. `Cart.OpKKos`.
+fn full_key(namespace: Option<&Wtf8Atom>, key: &Wtf8Atom) -> Wtf8Atom {
+    namespace.map_or(key.clone(), |namespace| {
+        [&*namespace.to_string_lossy(), &*key.to_string_lossy()]
+            .join(NAMESPACE_SEPARATOR)
+            .into()
+    })
+}
+
+/// Reads the module specifier of `require('...')` and `import('...')`
+fn module_source(call: &CallExpr) -> Option<Wtf8Atom> {
+    match &call.callee {
+        Callee::Import(_) => {}
+        Callee::Expr(box Expr::Ident(callee)) if callee.sym == "require" => {}
+        _ => return None,
+    }
+
+    match call.args.first().map(|arg| &*arg.expr) {
+        Some(Expr::Lit(Lit::Str(s))) => Some(s.value.clone()),
+        _ => None,
+    }
+}
+
+/// Checks for names of components like `Profile` and hooks like `useLabels`.
+/// Whether a hook is `useExtracted` or `getExtracted` from `next-intl`, whose
+/// signature is known.
+fn is_builtin_hook(source: &Wtf8Atom, name: &str) -> bool {
+    HookType::from_source(source.as_bytes())
+        .is_some_and(|hook_type| hook_type.extracted_name() == name)
+}
+
+/// The value that a function body returns with its last statement, e.g. `t`
+/// in `return t`.
+fn last_return_value(body: &mut BlockStmt) -> Option<&mut Expr> {
+    match body.stmts.last_mut()? {
+        Stmt::Return(ReturnStmt { arg: Some(arg), .. }) => Some(arg),
+        _ => None,
+    }
+}
+
+fn is_component_name(name: &str) -> bool {
+    let mut chars = name.chars();
+    match chars.next() {
+        Some(first) if first.is_uppercase() => true,
+        Some('u') => name
+            .strip_prefix("use")
+            .and_then(|rest| rest.chars().next())
+            .is_some_and(|next| next.is_uppercase()),
+        _ => false,
+    }
+}
+
+/// Locates the function of a component, e.g. `() => ...` in `memo(() => ...)`.
+fn component_fn_span(expr: &Expr) -> Option<Span> {
+    match unwrap_expr(expr) {
+        Expr::Arrow(arrow) => Some(arrow.span),
+        Expr::Fn(FnExpr { function, .. }) => Some(function.span),
+        Expr::Call(CallExpr {
+            callee: Callee::Expr(callee),
+            args,
+            ..
+        }) => {
+            let wrapper = match unwrap_expr(callee) {
+                Expr::Ident(ident) => &ident.sym,
+                Expr::Member(MemberExpr {
+                    prop: MemberProp::Ident(prop),
+                    ..
+                }) => &prop.sym,
+                _ => return None,
+            };
+            if !matches!(&**wrapper, "memo" | "forwardRef") {
+                return None;
+            }
+            component_fn_span(&args.first()?.expr)
+        }
+        _ => None,
+    }
+}
+
+/// Checks for `Promise.all(...)`
+fn is_promise_all(call: &CallExpr) -> bool {
+    matches!(
+        &call.callee,
+        Callee::Expr(box Expr::Member(MemberExpr {
+            obj: box Expr::Ident(obj),
+            prop: MemberProp::Ident(prop),
+            ..
+        })) if obj.sym == "Promise" && prop.sym == "all"
+    )
+}
+
+/// Checks whether a translator can be bound to a binding that may already
+/// hold one. Translators with different namespaces are reported, since
+/// messages can only be extracted for a single one.
+fn can_bind_translator(
+    existing: Option<&TranslatorInfo>,
+    translator: &TranslatorInfo,
+    span: Span,
+    conflict_message: impl FnOnce() -> String,
+) -> bool {
+    match existing {
+        Some(existing) if existing.namespace != translator.namespace => {
+            emit_error(span, &conflict_message());
+            false
+        }
+        Some(_) => false,
+        None => true,
+    }
+}
+
+fn emit_error(span: Span, message: &str) {
+    HANDLER.with(|handler| {
+        handler.struct_span_err(span, message).emit();
+    })
+}
+
+fn emit_warning(span: Span, message: &str) {
+    HANDLER.with(|handler| {
+        handler.struct_span_warn(span, message).emit();
+    })
+}
+
+fn warn_dynamic_expression(expr: &Expr) {
+    HANDLER.with(|handler| {
+        handler
+            .struct_span_err(
+                expr.span(),
+                "Cannot extract message from dynamic expression, messages need to be statically \
+                 analyzable. If you need to provide runtime values, pass them as a separate \
+                 argument.",
+            )
+            .emit();
+    })
+}
+
+/// Replaces the static strings found by `extract_static_messages` in the
+/// same order.
+fn replace_static_strings(value: &mut Expr, replace: &mut impl FnMut(&mut Expr)) {
+    match unwrap_expr_mut(value) {
+        Expr::Cond(CondExpr { cons, alt, .. }) => {
+            replace_static_strings(cons, replace);
+            replace_static_strings(alt, replace);
+        }
+        _ => replace(value),
+    }
 }
 
 fn extract_static_string(value: &Expr) -> Option<Wtf8Atom> {
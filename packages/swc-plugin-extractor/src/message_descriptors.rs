@@ -0,0 +1,189 @@
+use rustc_hash::{FxHashMap, FxHashSet};
+use swc_ecma_ast::*;
+use swc_ecma_utils::find_pat_ids;
+use swc_ecma_visit::{Visit, VisitWith};
+
+use crate::static_eval::unwrap_expr;
+
+/// Functions from `next-intl` that define messages outside of components and
+/// are compiled to descriptors like `{id: 'OpKKos', message: 'Hello!'}`.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum MessageMarker {
+    /// `msg('Pending')`
+    Msg,
+
+    /// `defineMessages({pending: 'Pending'})`
+    DefineMessages,
+}
+
+impl MessageMarker {
+    pub fn from_name(name: &str) -> Option<Self> {
+        match name {
+            "msg" => Some(MessageMarker::Msg),
+            "defineMessages" => Some(MessageMarker::DefineMessages),
+            _ => None,
+        }
+    }
+}
+
+/// Finds bindings that hold descriptors so that `t(labels.pending)` can be
+/// compiled regardless of the declaration order. Besides bindings like
+/// `labels` in `const labels = defineMessages({...})`, this includes bindings
+/// derived from them (e.g. `item` in `navItems.map((item) => ...)`). Imports
+/// are not considered, since other modules aren't analyzed.
+pub fn collect_descriptor_bindings<N>(
+    node: &N,
+    markers: &FxHashMap<Id, MessageMarker>,
+) -> FxHashSet<Id>
+where
+    N: for<'a> VisitWith<DescriptorCollector<'a>>,
+{
+    let mut collector = DescriptorCollector {
+        markers,
+        bindings: Default::default(),
+    };
+
+    // Derived bindings can precede the ones they are derived from
+    loop {
+        let len = collector.bindings.len();
+        node.visit_with(&mut collector);
+        if collector.bindings.len() == len {
+            break;
+        }
+    }
+
+    collector.bindings
+}
+
+/// Checks if an expression refers to a descriptor binding, e.g. `labels`,
+/// `labels.pending` or `labels[status]`.
+pub fn is_descriptor_expr(expr: &Expr, bindings: &FxHashSet<Id>) -> bool {
+    match unwrap_expr(expr) {
+        Expr::Ident(ident) => bindings.contains(&ident.to_id()),
+        Expr::Member(MemberExpr { obj, .. }) => is_descriptor_expr(obj, bindings),
+        _ => false,
+    }
+}
+
+pub struct DescriptorCollector<'a> {
+    markers: &'a FxHashMap<Id, MessageMarker>,
+    bindings: FxHashSet<Id>,
+}
+
+impl DescriptorCollector<'_> {
+    /// Checks if iterating over an expression yields descriptors, e.g.
+    /// `navItems`, `navItems.filter(...)` or `Object.values(labels)`.
+    fn is_descriptor_collection(&self, expr: &Expr) -> bool {
+        match unwrap_expr(expr) {
+            Expr::Call(CallExpr {
+                callee: Callee::Expr(callee),
+                args,
+                ..
+            }) => match unwrap_expr(callee) {
+                Expr::Member(MemberExpr {
+                    obj,
+                    prop: MemberProp::Ident(prop),
+                    ..
+                }) if prop.sym == "values"
+                    && matches!(unwrap_expr(obj), Expr::Ident(object) if object.sym == "Object") =>
+                {
+                    args.first()
+                        .is_some_and(|arg| self.is_descriptor_collection(&arg.expr))
+                }
+                Expr::Member(MemberExpr { obj, .. }) => self.is_descriptor_collection(obj),
+                _ => false,
+            },
+            expr => is_descriptor_expr(expr, &self.bindings),
+        }
+    }
+
+    fn add_pat(&mut self, pat: &Pat) {
+        let ids: Vec<Id> = find_pat_ids(pat);
+        self.bindings.extend(ids);
+    }
+}
+
+impl Visit for DescriptorCollector<'_> {
+    fn visit_var_declarator(&mut self, node: &VarDeclarator) {
+        if let Some(init) = &node.init {
+            if let Pat::Ident(name) = &node.name {
+                let mut finder = MarkerCallFinder {
+                    markers: self.markers,
+                    found: false,
+                };
+                init.visit_with(&mut finder);
+                if finder.found {
+                    self.bindings.insert(name.to_id());
+                }
+            }
+
+            // `const {pending} = labels`
+            if is_descriptor_expr(init, &self.bindings) {
+                self.add_pat(&node.name);
+            }
+        }
+
+        node.visit_children_with(self);
+    }
+
+    fn visit_call_expr(&mut self, node: &CallExpr) {
+        // `navItems.map((item) => ...)`
+        if let Callee::Expr(callee) = &node.callee {
+            if let Expr::Member(MemberExpr { obj, .. }) = unwrap_expr(callee) {
+                if self.is_descriptor_collection(obj) {
+                    for arg in &node.args {
+                        match unwrap_expr(&arg.expr) {
+                            Expr::Arrow(arrow) => {
+                                if let Some(param) = arrow.params.first() {
+                                    self.add_pat(param);
+                                }
+                            }
+                            Expr::Fn(FnExpr { function, .. }) => {
+                                if let Some(param) = function.params.first() {
+                                    self.add_pat(&param.pat);
+                                }
+                            }
+                            _ => {}
+                        }
+                    }
+                }
+            }
+        }
+
+        node.visit_children_with(self);
+    }
+
+    fn visit_for_of_stmt(&mut self, node: &ForOfStmt) {
+        // `for (const item of navItems)`
+        if self.is_descriptor_collection(&node.right) {
+            match &node.left {
+                ForHead::VarDecl(decl) => {
+                    for decl in &decl.decls {
+                        self.add_pat(&decl.name);
+                    }
+                }
+                ForHead::Pat(pat) => self.add_pat(pat),
+                ForHead::UsingDecl(_) => {}
+            }
+        }
+
+        node.visit_children_with(self);
+    }
+}
+struct MarkerCallFinder<'a> {
+    markers: &'a FxHashMap<Id, MessageMarker>,
+    found: bool,
+}
+
+impl Visit for MarkerCallFinder<'_> {
+    fn visit_call_expr(&mut self, node: &CallExpr) {
+        if let Callee::Expr(box Expr::Ident(callee)) = &node.callee {
+            if self.markers.contains_key(&callee.to_id()) {
+                self.found = true;
+                return;
+            }
+        }
+
+        node.visit_children_with(self);
+    }
+}
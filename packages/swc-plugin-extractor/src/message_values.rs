@@ -0,0 +1,151 @@
+use rustc_hash::FxHashSet;
+use swc_common::{Span, Spanned};
+use swc_ecma_ast::*;
+
+use crate::{
+    emit_warning,
+    icu_message::{parse_message, ArgumentKind},
+    message_text_span,
+    static_eval::{unwrap_expr, StaticString},
+};
+
+/// A property of a `values` object like `name: 'Jane'` or `{count}`.
+struct ValueProp<'a> {
+    name: String,
+    span: Span,
+
+    /// `None` for shorthands and methods, e.g. `b(chunks) {...}`
+    value: Option<&'a Expr>,
+}
+
+/// Compares the arguments of a message with the `values` that are passed
+/// along with it, e.g. reports `{name}` if there's no value for it. Values
+/// that aren't an object literal with static keys are skipped.
+pub fn check_message_values(
+    message_node: &Expr,
+    message_texts: &[StaticString],
+    values: Option<&Expr>,
+) {
+    let props = match values.map(unwrap_expr) {
+        None => Vec::new(),
+        Some(Expr::Object(object)) => {
+            let Some(props) = value_props(object) else {
+                return;
+            };
+            props
+        }
+        Some(_) => return,
+    };
+
+    let mut used = FxHashSet::default();
+    let mut is_complete = true;
+    for message in message_texts {
+        let text = message.value.to_string_lossy();
+        let Ok(arguments) = parse_message(&text) else {
+            // Syntax errors are reported separately
+            is_complete = false;
+            continue;
+        };
+
+        let mut reported = FxHashSet::default();
+        for argument in arguments {
+            used.insert(argument.name.to_string());
+            let argument_span = || {
+                message_text_span(Some(message_node), &text, argument.start, argument.end)
+                    .unwrap_or(message_node.span())
+            };
+
+            let Some(prop) = props.iter().find(|prop| prop.name == argument.name) else {
+                if reported.insert(argument.name) {
+                    emit_warning(
+                        argument_span(),
+                        &if argument.kind == ArgumentKind::Tag {
+                            format!(
+                                "No value is passed for the tag `<{}>`, e.g. `{{{}: (chunks) => \
+                                 ...}}`.",
+                                argument.name, argument.name
+                            )
+                        } else {
+                            format!(
+                                "No value is passed for the argument `{{{}}}`.",
+                                argument.name
+                            )
+                        },
+                    );
+                }
+                continue;
+            };
+
+            let kind = match argument.kind {
+                ArgumentKind::Number => "number",
+                ArgumentKind::Plural => "plural",
+                ArgumentKind::SelectOrdinal => "selectordinal",
+                _ => continue,
+            };
+            if let Some(value @ (Expr::Lit(Lit::Str(_)) | Expr::Tpl(_))) =
+                prop.value.map(unwrap_expr)
+            {
+                emit_warning(
+                    value.span(),
+                    &format!(
+                        "`{}` is used as a `{kind}` argument, but a string is passed. Pass a \
+                         number instead.",
+                        argument.name
+                    ),
+                );
+            }
+        }
+    }
+
+    if is_complete {
+        for prop in props.iter().filter(|prop| !used.contains(&prop.name)) {
+            emit_warning(
+                prop.span,
+                &format!(
+                    "The value `{}` isn't used in the message. Remove it or add `{{{}}}` to the \
+                     message.",
+                    prop.name, prop.name
+                ),
+            );
+        }
+    }
+}
+
+/// Returns `None` if the names of the properties aren't known, e.g. due to
+/// a spread or a computed key.
+fn value_props(object: &ObjectLit) -> Option<Vec<ValueProp<'_>>> {
+    object
+        .props
+        .iter()
+        .map(|prop| {
+            let PropOrSpread::Prop(prop) = prop else {
+                return None;
+            };
+            let (key, value) = match &**prop {
+                Prop::Shorthand(ident) => {
+                    return Some(ValueProp {
+                        name: ident.sym.to_string(),
+                        span: ident.span,
+                        value: None,
+                    })
+                }
+                Prop::KeyValue(KeyValueProp { key, value }) => (key, Some(&**value)),
+                Prop::Method(MethodProp { key, .. })
+                | Prop::Getter(GetterProp { key, .. })
+                | Prop::Setter(SetterProp { key, .. }) => (key, None),
+                Prop::Assign(_) => return None,
+            };
+            let name = match key {
+                PropName::Ident(ident) => ident.sym.to_string(),
+                PropName::Str(s) => s.value.to_string_lossy().into_owned(),
+                PropName::Num(num) => num.value.to_string(),
+                PropName::Computed(_) | PropName::BigInt(_) => return None,
+            };
+            Some(ValueProp {
+                name,
+                span: key.span(),
+                value,
+            })
+        })
+        .collect()
+}
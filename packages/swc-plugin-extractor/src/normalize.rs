@@ -0,0 +1,81 @@
+use icu_normalizer::ComposingNormalizerBorrowed;
+use serde::Deserialize;
+
+/// How messages are canonicalized before their key is generated, so that
+/// texts which only differ in their encoding share a key. The canonical text
+/// is also the one that is extracted. All steps are opt-in, since they can
+/// change the keys of existing messages.
+#[derive(Debug, Clone, Default, Deserialize)]
+#[serde(rename_all = "camelCase", default)]
+pub struct NormalizeConfig {
+    /// Composes characters like `e` followed by a combining accent to `é`
+    pub nfc: bool,
+
+    /// Replaces `\r\n` and `\r` with `\n`
+    pub newlines: bool,
+
+    /// Removes the indentation that multi-line template literals share, as
+    /// well as the line breaks that surround them
+    pub dedent: bool,
+
+    /// Replaces every sequence of whitespace with a single space
+    pub collapse_whitespace: bool,
+
+    /// Removes leading and trailing whitespace
+    pub trim: bool,
+}
+
+impl NormalizeConfig {
+    pub fn apply(&self, message: &str) -> String {
+        let mut message = message.to_string();
+        if self.newlines {
+            message = message.replace("\r\n", "\n").replace('\r', "\n");
+        }
+        if self.dedent && message.contains('\n') {
+            message = dedent(&message);
+        }
+        if self.collapse_whitespace {
+            message = message.split_whitespace().collect::<Vec<_>>().join(" ");
+        }
+        if self.trim {
+            message = message.trim().to_string();
+        }
+        if self.nfc {
+            message = ComposingNormalizerBorrowed::new_nfc()
+                .normalize(&message)
+                .into_owned();
+        }
+        message
+    }
+}
+
+/// Removes the line breaks at the start and end of a text along with the
+/// indentation of its lines, e.g. of
+///
+/// ```js
+/// t(`
+///   Welcome back!
+///   You have new messages.
+/// `);
+/// ```
+fn dedent(text: &str) -> String {
+    let mut lines = text.split('\n').collect::<Vec<_>>();
+    if lines.len() > 1 && lines[0].trim().is_empty() {
+        lines.remove(0);
+    }
+    if lines.len() > 1 && lines[lines.len() - 1].trim().is_empty() {
+        lines.pop();
+    }
+
+    let indent = lines
+        .iter()
+        .filter(|line| !line.trim().is_empty())
+        .map(|line| line.len() - line.trim_start().len())
+        .min()
+        .unwrap_or(0);
+    lines
+        .iter()
+        .map(|line| line.get(indent..).unwrap_or_else(|| line.trim_start()))
+        .collect::<Vec<_>>()
+        .join("\n")
+}
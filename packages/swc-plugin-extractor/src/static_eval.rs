@@ -0,0 +1,199 @@
+use rustc_hash::FxHashMap;
+use swc_atoms::{wtf8::Wtf8Buf, Atom, Wtf8Atom};
+use swc_ecma_ast::*;
+
+/// A string that is known at compile time.
+#[derive(Debug, Clone)]
+pub struct StaticString {
+    pub value: Wtf8Atom,
+
+    /// The module-scope constants the value was composed of, in the order
+    /// they are referenced
+    pub constants: Vec<Atom>,
+}
+
+/// Evaluates expressions to strings, e.g. `'Your subscription ' + LABEL`,
+/// where `LABEL` is a `const` declared at the top level of the module.
+#[derive(Debug, Default)]
+pub struct StaticEvaluator {
+    /// The values of constants that evaluate to strings, each evaluated once
+    /// so that constants referencing each other don't multiply the work.
+    constants: FxHashMap<Id, StaticString>,
+}
+
+impl StaticEvaluator {
+    pub fn collect_module(module: &Module) -> Self {
+        let mut inits = Vec::new();
+        for item in &module.body {
+            match item {
+                ModuleItem::Stmt(Stmt::Decl(Decl::Var(var)))
+                | ModuleItem::ModuleDecl(ModuleDecl::ExportDecl(ExportDecl {
+                    decl: Decl::Var(var),
+                    ..
+                })) => collect_const_inits(var, &mut inits),
+                _ => {}
+            }
+        }
+        Self::from_inits(inits)
+    }
+
+    pub fn collect_script(script: &Script) -> Self {
+        let mut inits = Vec::new();
+        for stmt in &script.body {
+            if let Stmt::Decl(Decl::Var(var)) = stmt {
+                collect_const_inits(var, &mut inits);
+            }
+        }
+        Self::from_inits(inits)
+    }
+
+    /// Evaluates constants in declaration order, which resolves references
+    /// to earlier constants from the cache.
+    fn from_inits(inits: Vec<(Id, &Expr)>) -> Self {
+        let order = inits.iter().map(|(id, _)| id.clone()).collect::<Vec<_>>();
+        let inits = inits.into_iter().collect::<FxHashMap<_, _>>();
+        let mut resolved = FxHashMap::default();
+        for id in &order {
+            resolve_constant(id, &inits, &mut resolved);
+        }
+
+        Self {
+            constants: resolved
+                .into_iter()
+                .filter_map(|(id, value)| Some((id, value?)))
+                .collect(),
+        }
+    }
+
+    pub fn eval(&self, expr: &Expr) -> Option<StaticString> {
+        let mut constants = Vec::new();
+        let value = eval_expr(
+            expr,
+            &mut |id| self.constants.get(id).cloned(),
+            &mut constants,
+        )?;
+        Some(StaticString {
+            value: value.into(),
+            constants,
+        })
+    }
+}
+
+fn collect_const_inits<'a>(var: &'a VarDecl, inits: &mut Vec<(Id, &'a Expr)>) {
+    if var.kind != VarDeclKind::Const {
+        return;
+    }
+
+    for decl in &var.decls {
+        if let (Pat::Ident(name), Some(init)) = (&decl.name, &decl.init) {
+            inits.push((name.to_id(), &**init));
+        }
+    }
+}
+
+/// Evaluates a constant, unless it's already evaluated. Cycles like
+/// `const A = B; const B = A;` evaluate to `None`.
+fn resolve_constant(
+    id: &Id,
+    inits: &FxHashMap<Id, &Expr>,
+    resolved: &mut FxHashMap<Id, Option<StaticString>>,
+) -> Option<StaticString> {
+    if let Some(value) = resolved.get(id) {
+        return value.clone();
+    }
+    let init = inits.get(id)?;
+
+    // Marks the constant as being evaluated
+    resolved.insert(id.clone(), None);
+    let mut constants = Vec::new();
+    let value = eval_expr(
+        init,
+        &mut |id| resolve_constant(id, inits, resolved),
+        &mut constants,
+    )
+    .map(|value| StaticString {
+        value: value.into(),
+        constants,
+    });
+    resolved.insert(id.clone(), value.clone());
+    value
+}
+
+/// Evaluates an expression with `resolve` providing the values of
+/// constants, whose names are collected in `constants`.
+fn eval_expr(
+    expr: &Expr,
+    resolve: &mut impl FnMut(&Id) -> Option<StaticString>,
+    constants: &mut Vec<Atom>,
+) -> Option<Wtf8Buf> {
+    match expr {
+        Expr::Lit(Lit::Str(s)) => Some((&s.value).into()),
+
+        Expr::Tpl(tpl) => {
+            let mut value = Wtf8Buf::new();
+            for (index, quasi) in tpl.quasis.iter().enumerate() {
+                value.push_wtf8(quasi.cooked.as_ref()?);
+                if let Some(expr) = tpl.exprs.get(index) {
+                    value.push_wtf8(&eval_expr(expr, resolve, constants)?);
+                }
+            }
+            Some(value)
+        }
+
+        Expr::Bin(BinExpr {
+            op: BinaryOp::Add,
+            left,
+            right,
+            ..
+        }) => {
+            let mut value = eval_expr(left, resolve, constants)?;
+            value.push_wtf8(&eval_expr(right, resolve, constants)?);
+            Some(value)
+        }
+
+        Expr::Paren(_)
+        | Expr::TsNonNull(_)
+        | Expr::TsAs(_)
+        | Expr::TsSatisfies(_)
+        | Expr::TsConstAssertion(_)
+        | Expr::TsTypeAssertion(_) => eval_expr(unwrap_expr(expr), resolve, constants),
+
+        Expr::Ident(ident) => {
+            let constant = resolve(&ident.to_id())?;
+            for name in std::iter::once(&ident.sym).chain(&constant.constants) {
+                if !constants.contains(name) {
+                    constants.push(name.clone());
+                }
+            }
+            Some((&constant.value).into())
+        }
+
+        _ => None,
+    }
+}
+
+/// Skips syntax that doesn't affect the value at runtime, e.g. `(t)`, `t!`
+/// or `'Hello!' as const`.
+pub fn unwrap_expr(expr: &Expr) -> &Expr {
+    match expr {
+        Expr::Paren(ParenExpr { expr, .. })
+        | Expr::TsNonNull(TsNonNullExpr { expr, .. })
+        | Expr::TsAs(TsAsExpr { expr, .. })
+        | Expr::TsSatisfies(TsSatisfiesExpr { expr, .. })
+        | Expr::TsConstAssertion(TsConstAssertion { expr, .. })
+        | Expr::TsTypeAssertion(TsTypeAssertion { expr, .. }) => unwrap_expr(expr),
+        _ => expr,
+    }
+}
+
+pub fn unwrap_expr_mut(expr: &mut Expr) -> &mut Expr {
+    match expr {
+        Expr::Paren(ParenExpr { expr, .. })
+        | Expr::TsNonNull(TsNonNullExpr { expr, .. })
+        | Expr::TsAs(TsAsExpr { expr, .. })
+        | Expr::TsSatisfies(TsSatisfiesExpr { expr, .. })
+        | Expr::TsConstAssertion(TsConstAssertion { expr, .. })
+        | Expr::TsTypeAssertion(TsTypeAssertion { expr, .. }) => unwrap_expr_mut(expr),
+        _ => expr,
+    }
+}
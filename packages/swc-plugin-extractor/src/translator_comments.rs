@@ -0,0 +1,65 @@
+use swc_common::comments::{Comment, CommentKind};
+
+/// Reads a description for translators from a comment like
+/// `// i18n: Shown on the empty cart page` or
+/// `/** @description Shown on the empty cart page */`.
+pub fn comment_description(comment: &Comment) -> Option<String> {
+    let lines = comment.text.lines().map(|line| match comment.kind {
+        // Leading `*` of JSDoc lines
+        CommentKind::Block => line.trim().trim_start_matches('*').trim(),
+        CommentKind::Line => line.trim(),
+    });
+
+    let mut description: Option<Vec<&str>> = None;
+    for line in lines {
+        match &mut description {
+            None => {
+                if let Some(text) = line
+                    .strip_prefix("i18n:")
+                    .or_else(|| line.strip_prefix("@description"))
+                {
+                    description = Some(vec![text.trim()]);
+                }
+            }
+
+            // Continues until the next tag
+            Some(_) if line.starts_with('@') => break,
+            Some(description) => description.push(line),
+        }
+    }
+
+    let description = description?
+        .into_iter()
+        .filter(|line| !line.is_empty())
+        .collect::<Vec<_>>()
+        .join(" ");
+    (!description.is_empty()).then_some(description)
+}
+
+/// A comment on a message whose text was changed, which keeps the key of
+/// the previous text so existing translations are carried over, e.g.
+/// `t(/* keep: Helo! */ 'Hello!')`.
+pub enum KeepComment {
+    /// `/* keep */`
+    Bare,
+
+    /// `/* keep: Helo! */`
+    PreviousMessage(String),
+
+    /// `/* keep-id: OpKKos */`
+    PreviousId(String),
+}
+
+impl KeepComment {
+    pub fn parse(comment: &Comment) -> Option<Self> {
+        let text = comment.text.trim();
+        if text == "keep" {
+            Some(KeepComment::Bare)
+        } else if let Some(id) = text.strip_prefix("keep-id:") {
+            Some(KeepComment::PreviousId(id.trim().to_string()))
+        } else {
+            text.strip_prefix("keep:")
+                .map(|message| KeepComment::PreviousMessage(message.trim().to_string()))
+        }
+    }
+}
@@ -1,12 +1,15 @@
-use std::cell::RefCell;
 use std::fs;
-use std::path::PathBuf;
-use std::rc::Rc;
+use std::path::{Path, PathBuf};
+use std::sync::{Arc, Mutex};
 
 use serde_json::Value;
-use swc_common::{FileName, Globals, Mark, SourceMap, GLOBALS};
+use swc_common::{
+    comments::{Comments, SingleThreadedComments},
+    errors::{Diagnostic, DiagnosticBuilder, Emitter, Handler, HandlerFlags, HANDLER},
+    FileName, Globals, Mark, SourceMap, GLOBALS,
+};
 use swc_core::ecma::{
-    parser::{lexer::Lexer, EsSyntax, Parser, StringInput, Syntax},
+    parser::{lexer::Lexer, EsSyntax, Parser, StringInput, Syntax, TsSyntax},
     transforms::{
         base::resolver,
         testing::{test_fixture, FixtureTestConfig},
@@ -14,61 +17,127 @@ use swc_core::ecma::{
 };
 use swc_ecma_ast::{EsVersion, Pass};
 use swc_ecma_visit::VisitMutWith;
-use swc_plugin_extractor::TransformVisitor;
+use swc_plugin_extractor::{Config, TransformVisitor};
 
 struct VisitorPass {
-    visitor: Rc<RefCell<TransformVisitor>>,
+    visitor: TransformVisitor,
 }
 
 impl Pass for VisitorPass {
     fn process(&mut self, program: &mut swc_ecma_ast::Program) {
-        program.visit_mut_with(&mut *self.visitor.borrow_mut());
+        // The handler of `test_fixture` drops warnings, therefore they are
+        // collected separately and forwarded to be included in `output.stderr`
+        let diagnostics = Arc::new(Mutex::new(Vec::new()));
+        let handler = Handler::with_emitter_and_flags(
+            Box::new(DiagnosticCollector(diagnostics.clone())),
+            HandlerFlags {
+                can_emit_warnings: true,
+                ..Default::default()
+            },
+        );
+        HANDLER.set(&handler, || program.visit_mut_with(&mut self.visitor));
+
+        HANDLER.with(|handler| {
+            for diagnostic in diagnostics.lock().unwrap().drain(..) {
+                DiagnosticBuilder::new_diagnostic(handler, diagnostic).emit();
+            }
+        });
     }
 }
 
-fn tr(visitor_rc: Rc<RefCell<TransformVisitor>>) -> impl Pass {
+struct DiagnosticCollector(Arc<Mutex<Vec<Diagnostic>>>);
+
+impl Emitter for DiagnosticCollector {
+    fn emit(&mut self, db: &mut DiagnosticBuilder<'_>) {
+        self.0.lock().unwrap().push((**db).clone());
+    }
+}
+
+fn tr(visitor: TransformVisitor) -> impl Pass {
     let unresolved_mark = Mark::new();
     let top_level_mark = Mark::new();
 
     (
         resolver(unresolved_mark, top_level_mark, false),
-        VisitorPass {
-            visitor: visitor_rc,
-        },
+        VisitorPass { visitor },
     )
 }
 
-fn parse(cm: &SourceMap, code: &str) -> swc_ecma_ast::Program {
+fn parse(
+    cm: &SourceMap,
+    comments: &SingleThreadedComments,
+    code: &str,
+    syntax: Syntax,
+) -> swc_ecma_ast::Program {
     let fm = cm.new_source_file(FileName::Anon.into(), code.to_string());
     let lexer = Lexer::new(
-        Syntax::Es(EsSyntax {
-            jsx: true,
-            ..Default::default()
-        }),
+        syntax,
         EsVersion::EsNext,
         StringInput::from(&*fm),
-        None,
+        Some(comments),
     );
     let mut parser = Parser::new_from(lexer);
     parser.parse_program().unwrap()
 }
 
+/// Reads the plugin config, optionally extended by a `config.json` in the
+/// fixture directory.
+fn config(dir: &Path, file_path: String) -> Config {
+    let mut config = serde_json::json!({
+        "isDevelopment": true,
+        "filePath": file_path,
+    });
+    if let Ok(overrides) = fs::read_to_string(dir.join("config.json")) {
+        let overrides: Value = serde_json::from_str(&overrides)
+            .unwrap_or_else(|_| panic!("Failed to parse config.json in {dir:?}"));
+        for (key, value) in overrides.as_object().unwrap() {
+            config[key] = value.clone();
+        }
+    }
+    serde_json::from_value(config).unwrap()
+}
+
 #[testing::fixture("tests/fixture/**/input.js")]
 fn test(input: PathBuf) {
+    run(
+        input,
+        "js",
+        Syntax::Es(EsSyntax {
+            jsx: true,
+            ..Default::default()
+        }),
+    );
+}
+
+#[testing::fixture("tests/fixture/**/input.tsx")]
+fn test_ts(input: PathBuf) {
+    run(
+        input,
+        "tsx",
+        Syntax::Typescript(TsSyntax {
+            tsx: true,
+            ..Default::default()
+        }),
+    );
+}
+
+fn run(input: PathBuf, extension: &str, syntax: Syntax) {
     let dir = input.parent().unwrap().to_path_buf();
-    let output = dir.join("output.js");
+    let output = dir.join(format!("output.{extension}"));
     let output_json = dir.join("output.json");
 
-    let visitor = TransformVisitor::new(true, "input.js".to_string(), None);
-    let visitor_rc = Rc::new(RefCell::new(visitor));
+    let file_name = input.file_name().unwrap().to_string_lossy().to_string();
 
     // Test JS transformation
     test_fixture(
-        Syntax::Es(EsSyntax {
-            jsx: true,
-            ..Default::default()
-        }),
-        &|_| tr(visitor_rc.clone()),
+        syntax,
+        &|t| {
+            tr(TransformVisitor::new(
+                config(&dir, file_name.clone()),
+                None,
+                Some(Box::new((*t.comments).clone()) as Box<dyn Comments>),
+            ))
+        },
         &input,
         &output,
         FixtureTestConfig {
@@ -83,35 +152,33 @@ fn test(input: PathBuf) {
     GLOBALS.set(&globals, || {
         let code = fs::read_to_string(&input).unwrap();
         let cm = SourceMap::default();
-        let mut program = parse(&cm, &code);
-
-        if !program.is_module() {
-            panic!("Parsed as script, expected module");
-        }
+        let comments = SingleThreadedComments::default();
+        let mut program = parse(&cm, &comments, &code, syntax);
 
         let unresolved_mark = Mark::new();
         let top_level_mark = Mark::new();
 
         program.visit_mut_with(&mut resolver(unresolved_mark, top_level_mark, false));
 
-        let file_name = input.file_name().unwrap().to_string_lossy().to_string();
         // Use the same SourceMap that was used for parsing so spans match
         let mut visitor = TransformVisitor::new(
-            true,
-            file_name,
+            config(&dir, file_name),
             Some(Box::new(cm) as Box<dyn swc_core::common::SourceMapper>),
+            Some(Box::new(comments) as Box<dyn Comments>),
         );
 
-        program.visit_mut_with(&mut visitor);
+        // Diagnostics are already covered by `output.stderr`
+        let handler = Handler::with_emitter_writer(Box::new(std::io::sink()), None);
+        HANDLER.set(&handler, || program.visit_mut_with(&mut visitor));
 
         // Use results directly from visitor - it calculates line numbers correctly with SourceMap
         let actual_results = visitor.get_results();
         let actual_json: Value = serde_json::to_value(&actual_results).unwrap();
 
         let expected_json_str = fs::read_to_string(&output_json)
-            .unwrap_or_else(|_| panic!("Expected output.json not found at {:?}", output_json));
+            .unwrap_or_else(|_| panic!("Expected output.json not found at {output_json:?}"));
         let expected_json: Value = serde_json::from_str(&expected_json_str)
-            .unwrap_or_else(|_| panic!("Failed to parse expected JSON at {:?}", output_json));
+            .unwrap_or_else(|_| panic!("Failed to parse expected JSON at {output_json:?}"));
 
         if actual_json != expected_json {
             panic!(
@@ -0,0 +1,9 @@
+import {getExtracted} from 'next-intl/server';
+
+async function Component({isAdmin}) {
+  let t = await getExtracted('User');
+  if (isAdmin) {
+    t = await getExtracted('Admin');
+  }
+  t('Welcome back');
+}
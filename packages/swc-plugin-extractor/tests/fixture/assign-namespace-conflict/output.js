@@ -0,0 +1,8 @@
+import { getTranslations as getTranslations$1 } from 'next-intl/server';
+async function Component({ isAdmin }) {
+    let t = await getTranslations$1('User');
+    if (isAdmin) {
+        t = await getTranslations$1('Admin');
+    }
+    t("UKxoV8", void 0, void 0, "Welcome back");
+}
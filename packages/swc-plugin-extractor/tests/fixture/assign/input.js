@@ -0,0 +1,18 @@
+import {useExtracted} from 'next-intl';
+import {getExtracted} from 'next-intl/server';
+
+function Component() {
+  let t;
+  t = useExtracted();
+  t('Hey!');
+}
+
+async function AsyncComponent({isAdmin}) {
+  let t;
+  if (isAdmin) {
+    t = await getExtracted('Admin');
+  } else {
+    t = await getExtracted('Admin');
+  }
+  t('Welcome back');
+}
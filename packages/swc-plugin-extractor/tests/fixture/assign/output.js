@@ -0,0 +1,16 @@
+import { useTranslations as useTranslations$1 } from 'next-intl';
+import { getTranslations as getTranslations$1 } from 'next-intl/server';
+function Component() {
+    let t;
+    t = useTranslations$1();
+    t("-YJVTi", void 0, void 0, "Hey!");
+}
+async function AsyncComponent({ isAdmin }) {
+    let t;
+    if (isAdmin) {
+        t = await getTranslations$1('Admin');
+    } else {
+        t = await getTranslations$1('Admin');
+    }
+    t("UKxoV8", void 0, void 0, "Welcome back");
+}
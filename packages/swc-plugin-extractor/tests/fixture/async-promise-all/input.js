@@ -0,0 +1,16 @@
+import {getExtracted} from 'next-intl/server';
+
+async function Page({params}) {
+  const [t, user, tNav] = await Promise.all([
+    getExtracted('Page'),
+    getUser(params.id),
+    getExtracted({namespace: 'Nav'})
+  ]);
+  t('Hello {name}!', {name: user.name});
+  tNav('Home');
+}
+
+async function Other() {
+  const [, t] = await Promise.all([loadData(), getExtracted()]);
+  t('Loaded');
+}
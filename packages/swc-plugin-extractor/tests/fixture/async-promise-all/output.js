@@ -0,0 +1,21 @@
+import { getTranslations as getTranslations$1 } from 'next-intl/server';
+async function Page({ params }) {
+    const [t, user, tNav] = await Promise.all([
+        getTranslations$1('Page'),
+        getUser(params.id),
+        getTranslations$1({
+            namespace: 'Nav'
+        })
+    ]);
+    t("wafoOY", {
+        name: user.name
+    }, void 0, "Hello {name}!");
+    tNav("ejEGdx", void 0, void 0, "Home");
+}
+async function Other() {
+    const [, t] = await Promise.all([
+        loadData(),
+        getTranslations$1()
+    ]);
+    t("dRYcjx", void 0, void 0, "Loaded");
+}
@@ -0,0 +1,17 @@
+import {getExtracted} from 'next-intl/server';
+
+export function GET(request, {params}) {
+  return getExtracted({locale: params.locale, namespace: 'api'}).then((t) =>
+    Response.json({title: t('Report')})
+  );
+}
+
+export function generateMetadata() {
+  return getExtracted().then(function (t) {
+    return {title: t('Dashboard')};
+  });
+}
+
+export function getTranslator() {
+  return getExtracted();
+}
@@ -0,0 +1,19 @@
+import { getTranslations as getTranslations$1 } from 'next-intl/server';
+export function GET(request, { params }) {
+    return getTranslations$1({
+        locale: params.locale,
+        namespace: 'api'
+    }).then((t)=>Response.json({
+            title: t("x5Tz6M", void 0, void 0, "Report")
+        }));
+}
+export function generateMetadata() {
+    return getTranslations$1().then(function(t) {
+        return {
+            title: t("hzSNj4", void 0, void 0, "Dashboard")
+        };
+    });
+}
+export function getTranslator() {
+    return getTranslations$1();
+}
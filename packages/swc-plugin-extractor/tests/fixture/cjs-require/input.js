@@ -0,0 +1,12 @@
+const {useExtracted, useFormatter} = require('next-intl');
+const {getExtracted: getT} = require('next-intl/server');
+
+function Component() {
+  const t = useExtracted();
+  t('Hey!');
+}
+
+async function AsyncComponent() {
+  const t = await getT('ui');
+  t('Hello!');
+}
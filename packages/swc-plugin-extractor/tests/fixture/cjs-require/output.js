@@ -0,0 +1,10 @@
+const { useTranslations: useTranslations$1, useFormatter } = require('next-intl');
+const { getTranslations: getTranslations$1 } = require('next-intl/server');
+function Component() {
+    const t = useTranslations$1();
+    t("-YJVTi", void 0, void 0, "Hey!");
+}
+async function AsyncComponent() {
+    const t = await getTranslations$1('ui');
+    t("OpKKos", void 0, void 0, "Hello!");
+}
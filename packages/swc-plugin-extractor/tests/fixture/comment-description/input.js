@@ -0,0 +1,51 @@
+import {useExtracted} from 'next-intl';
+
+function Cart({count}) {
+  const t = useExtracted();
+
+  // i18n: Shown on the empty cart page
+  t('Your cart is empty');
+
+  // i18n: Page title
+  const title = t('Cart');
+
+  /**
+   * @description Button that
+   *   proceeds to the payment
+   * @see https://example.com
+   */
+  t('Checkout');
+
+  // Regular comments are ignored
+  t('Continue shopping');
+
+  // i18n: Ignored in favor of the description property
+  t({message: 'Remove', description: 'Removes the item'});
+
+  return (
+    <p>
+      {/* i18n: Number of items in the cart */}
+      {t('{count} items', {count})}
+      {title}
+    </p>
+  );
+}
+
+/** @description The shopping cart */
+const MiniCart = () => {
+  const t = useExtracted();
+  return <p>{t('Your cart')}</p>;
+};
+
+// i18n: Applies to the call only
+function Summary({items}) {
+  const t = useExtracted();
+  // i18n: Number of items
+  const label = t('{count} items', {count: items.length});
+  return (
+    <ul>
+      {/* i18n: Item of the summary */}
+      {items.map((item) => <li>{t('Item {name}', {name: item.name})}</li>)}
+    </ul>
+  );
+}
@@ -0,0 +1,42 @@
+import { useTranslations as useTranslations$1 } from 'next-intl';
+function Cart({ count }) {
+    const t = useTranslations$1();
+    // i18n: Shown on the empty cart page
+    t("TtTPxt", void 0, void 0, "Your cart is empty");
+    // i18n: Page title
+    const title = t("2tqQFl", void 0, void 0, "Cart");
+    /**
+   * @description Button that
+   *   proceeds to the payment
+   * @see https://example.com
+   */ t("BJ2TKX", void 0, void 0, "Checkout");
+    // Regular comments are ignored
+    t("Yywm0p", void 0, void 0, "Continue shopping");
+    // i18n: Ignored in favor of the description property
+    t("G_yZLu", void 0, void 0, "Remove");
+    return <p>
+      { /* i18n: Number of items in the cart */ }
+      {t("ItDqLZ", {
+        count
+    }, void 0, "{count} items")}
+      {title}
+    </p>;
+}
+/** @description The shopping cart */ const MiniCart = ()=>{
+    const t = useTranslations$1();
+    return <p>{t("K_unOm", void 0, void 0, "Your cart")}</p>;
+};
+// i18n: Applies to the call only
+function Summary({ items }) {
+    const t = useTranslations$1();
+    // i18n: Number of items
+    const label = t("ItDqLZ", {
+        count: items.length
+    }, void 0, "{count} items");
+    return <ul>
+      { /* i18n: Item of the summary */ }
+      {items.map((item)=><li>{t("zVHtbg", {
+            name: item.name
+        }, void 0, "Item {name}")}</li>)}
+    </ul>;
+}
@@ -0,0 +1,12 @@
+import {useExtracted} from 'next-intl';
+
+function Component({isAdmin, count, label}) {
+  const t = useExtracted();
+  t(isAdmin ? 'Delete everything' : 'Delete');
+  t(count > 10 ? 'Many' : count > 1 ? `Some` : 'One');
+  t.rich(isAdmin ? '<b>Admin</b>' : 'User', {b: (chunks) => <b>{chunks}</b>});
+  t({message: isAdmin ? 'Save all' : 'Save', description: 'Button label'});
+  t(label ?? 'Untitled');
+  t('Draft' ?? 'Untitled');
+  t({id: 'greeting', message: isAdmin ? 'Hi admin' : 'Hi'});
+}
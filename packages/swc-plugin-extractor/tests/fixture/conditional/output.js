@@ -0,0 +1,63 @@
+import { useTranslations as useTranslations$1 } from 'next-intl';
+function Component({ isAdmin, count, label }) {
+    const t = useTranslations$1();
+    t(...isAdmin ? [
+        "AbtPB2",
+        void 0,
+        void 0,
+        "Delete everything"
+    ] : [
+        "K3r6DQ",
+        void 0,
+        void 0,
+        "Delete"
+    ]);
+    t(...count > 10 ? [
+        "88fCEj",
+        void 0,
+        void 0,
+        "Many"
+    ] : count > 1 ? [
+        "KqSX7J",
+        void 0,
+        void 0,
+        "Some"
+    ] : [
+        "phjUkw",
+        void 0,
+        void 0,
+        "One"
+    ]);
+    t.rich(...isAdmin ? [
+        "EzuiaL",
+        {
+            b: (chunks)=><b>{chunks}</b>
+        },
+        void 0,
+        "<b>Admin</b>"
+    ] : [
+        "EwRIOm",
+        {
+            b: (chunks)=><b>{chunks}</b>
+        },
+        void 0,
+        "User"
+    ]);
+    t(...isAdmin ? [
+        "N0sZf4",
+        void 0,
+        void 0,
+        "Save all"
+    ] : [
+        "jvo0vs",
+        void 0,
+        void 0,
+        "Save"
+    ]);
+    t(label ?? 'Untitled');
+    t('Draft' ?? 'Untitled');
+    t({
+        id: 'greeting',
+        message: isAdmin ? 'Hi admin' : 'Hi'
+    });
+}
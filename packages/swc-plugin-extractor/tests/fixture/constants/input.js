@@ -0,0 +1,21 @@
+import {useExtracted} from 'next-intl';
+
+const LABEL = 'Save';
+export const PRODUCT = `Acme`;
+const GREETING = `Welcome to ${PRODUCT}!`;
+let mutable = 'Mutable';
+const TAGLINE = `${GREETING} ${LABEL} your work.`;
+const LOOP = OTHER_LOOP;
+const OTHER_LOOP = LOOP;
+
+function Component() {
+  const t = useExtracted();
+  t('Your subscription ' + 'has expired.');
+  t(LABEL);
+  t(GREETING);
+  t(`${LABEL} changes`);
+  t({message: 'Thanks for using ' + PRODUCT, description: 'Footer'});
+  t(mutable);
+  t(TAGLINE);
+  t(LOOP);
+}
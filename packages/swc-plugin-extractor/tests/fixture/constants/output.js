@@ -0,0 +1,19 @@
+import { useTranslations as useTranslations$1 } from 'next-intl';
+const LABEL = 'Save';
+export const PRODUCT = `Acme`;
+const GREETING = `Welcome to ${PRODUCT}!`;
+let mutable = 'Mutable';
+const TAGLINE = `${GREETING} ${LABEL} your work.`;
+const LOOP = OTHER_LOOP;
+const OTHER_LOOP = LOOP;
+function Component() {
+    const t = useTranslations$1();
+    t("JQ0Al8", void 0, void 0, "Your subscription has expired.");
+    t("jvo0vs", void 0, void 0, "Save");
+    t("Ki445-", void 0, void 0, "Welcome to Acme!");
+    t("X0ha1a", void 0, void 0, "Save changes");
+    t("4veUNw", void 0, void 0, "Thanks for using Acme");
+    t(mutable);
+    t("ZQz3Vk", void 0, void 0, "Welcome to Acme! Save your work.");
+    t(LOOP);
+}
@@ -0,0 +1,13 @@
+import {Extracted, useExtracted} from 'next-intl';
+
+function Component() {
+  const t = useExtracted();
+  t('Open');
+  t({message: 'Open', context: 'file'});
+  t({message: 'Open', context: 'store status', description: 'Shown next to the opening hours'});
+  t({id: 'explicit', message: 'Open', context: 'door'});
+  t({message: /* keep: Opn */ 'Open', context: 'file'});
+  t({message: 'Open', context: getContext()});
+
+  return <Extracted context="menu">Open</Extracted>;
+}
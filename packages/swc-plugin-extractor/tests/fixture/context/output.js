@@ -0,0 +1,12 @@
+import { useTranslations as useTranslations$1 } from 'next-intl';
+function Component() {
+    const t$1 = useTranslations$1();
+    const t = useTranslations$1();
+    t("JfG49w", void 0, void 0, "Open");
+    t("nWoCia", void 0, void 0, "Open");
+    t("DVg9uX", void 0, void 0, "Open");
+    t("explicit", void 0, void 0, "Open");
+    t("fw1p0u", void 0, void 0, "Open");
+    t("JfG49w", void 0, void 0, "Open");
+    return t$1.rich("ucVQfk", void 0, void 0, "Open");
+}
@@ -0,0 +1,23 @@
+import {useExtracted} from 'next-intl';
+import {Link, useAppExtracted, getAppExtracted} from '@/i18n';
+import * as i18n from '@/i18n';
+
+function Component() {
+  const t = useAppExtracted('ui');
+  const tDefault = useExtracted();
+  t('Hello!');
+  tDefault('Hello!');
+  return <Link href="/">{t('Home')}</Link>;
+}
+
+async function AsyncComponent() {
+  const t = await getAppExtracted();
+  const t2 = await i18n.getAppExtracted('ui');
+  t('Hey!');
+  t2('Hey!');
+}
+
+function Configured({options}) {
+  const t = useAppExtracted(options);
+  t('Configured');
+}
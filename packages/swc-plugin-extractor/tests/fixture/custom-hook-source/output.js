@@ -0,0 +1,21 @@
+import { getTranslations as getTranslations$1 } from "next-intl/server";
+import { useTranslations as useTranslations$1 } from 'next-intl';
+import { Link, useTranslations as useTranslations$2 } from '@/i18n';
+import * as i18n from '@/i18n';
+function Component() {
+    const t = useTranslations$2('ui');
+    const tDefault = useTranslations$1();
+    t("OpKKos", void 0, void 0, "Hello!");
+    tDefault("OpKKos", void 0, void 0, "Hello!");
+    return <Link href="/">{t("ejEGdx", void 0, void 0, "Home")}</Link>;
+}
+async function AsyncComponent() {
+    const t = await getTranslations$1();
+    const t2 = await getTranslations$1('ui');
+    t("-YJVTi", void 0, void 0, "Hey!");
+    t2("-YJVTi", void 0, void 0, "Hey!");
+}
+function Configured({ options }) {
+    const t = useTranslations$2(options);
+    t("EgCzJw", void 0, void 0, "Configured");
+}
@@ -0,0 +1,8 @@
+'use client';
+
+import {useAppExtracted} from '@/i18n';
+
+export default function Component() {
+  const t = useAppExtracted();
+  return t('Hello!');
+}
@@ -0,0 +1,7 @@
+'use client';
+import { useTranslations as useTranslations$1 } from "next-intl";
+import '@/i18n';
+export default function Component() {
+    const t = useTranslations$1();
+    return t("OpKKos", void 0, void 0, "Hello!");
+}
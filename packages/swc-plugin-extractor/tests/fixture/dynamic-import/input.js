@@ -0,0 +1,5 @@
+export async function GET() {
+  const {getExtracted} = await import('next-intl/server');
+  const t = await getExtracted();
+  return Response.json({message: t('Hey!')});
+}
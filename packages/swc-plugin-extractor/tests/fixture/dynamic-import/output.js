@@ -0,0 +1,7 @@
+export async function GET() {
+    const { getTranslations: getTranslations$1 } = await import('next-intl/server');
+    const t = await getTranslations$1();
+    return Response.json({
+        message: t("-YJVTi", void 0, void 0, "Hey!")
+    });
+}
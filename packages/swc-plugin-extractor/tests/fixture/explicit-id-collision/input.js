@@ -0,0 +1,16 @@
+import {useExtracted} from 'next-intl';
+
+function Component() {
+  const t = useExtracted();
+
+  t({id: 'greeting', message: 'Hello'});
+  t({id: 'greeting', message: 'Hello'});
+  t({id: 'greeting', message: 'Hi'});
+
+  // Registered for a different message of another file
+  t({id: 'farewell', message: 'Bye'});
+
+  // Pinned to the key of a message that is still used
+  t(/* keep: Save */ 'Save all');
+  t('Save');
+}
@@ -0,0 +1,12 @@
+import { useTranslations as useTranslations$1 } from 'next-intl';
+function Component() {
+    const t = useTranslations$1();
+    t("greeting", void 0, void 0, "Hello");
+    t("greeting", void 0, void 0, "Hello");
+    t("greeting", void 0, void 0, "Hi");
+    // Registered for a different message of another file
+    t("farewell", void 0, void 0, "Bye");
+    // Pinned to the key of a message that is still used
+    t(/* keep: Save */ "jvo0vs", void 0, void 0, "Save all");
+    t("jvo0vs", void 0, void 0, "Save");
+}
@@ -0,0 +1,5 @@
+import { useTranslations as useTranslations$1 } from 'next-intl';
+function Component() {
+    const t = useTranslations$1();
+    t("greeting", void 0, void 0, "Hello!");
+}
@@ -0,0 +1,8 @@
+import {useExtracted} from 'next-intl';
+
+function Component() {
+  const t = useExtracted();
+  t({id: 'greeting', message: 'Hello!'});
+  t({id: 'Greeting', message: 'Hello!'});
+  t({id: 'checkoutTotal', message: 'Total'});
+}
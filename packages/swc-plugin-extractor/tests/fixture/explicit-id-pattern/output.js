@@ -0,0 +1,7 @@
+import { useTranslations as useTranslations$1 } from 'next-intl';
+function Component() {
+    const t = useTranslations$1();
+    t("greeting", void 0, void 0, "Hello!");
+    t("OpKKos", void 0, void 0, "Hello!");
+    t("MJ2jZQ", void 0, void 0, "Total");
+}
@@ -0,0 +1,17 @@
+import {Extracted, useExtracted} from 'next-intl';
+
+const ID = 'fromConstant';
+
+function Component({id}) {
+  const t = useExtracted();
+  t({id: 'valid_id-1', message: 'Valid'});
+  t({id: ID, message: 'Constant'});
+  t({id: '', message: 'Empty'});
+  t({id: '  ', message: 'Whitespace'});
+  t({id: 'carousel.next', message: 'Next'});
+  t({id: 'carousel next', message: 'Next'});
+  t({id: 'x'.repeat(3), message: 'Dynamic'});
+  t({id, message: 'Shorthand'});
+
+  return <Extracted id="footer.copyright">All rights reserved</Extracted>;
+}
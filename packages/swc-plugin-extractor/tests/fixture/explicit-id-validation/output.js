@@ -0,0 +1,15 @@
+import { useTranslations as useTranslations$1 } from 'next-intl';
+const ID = 'fromConstant';
+function Component({ id }) {
+    const t$1 = useTranslations$1();
+    const t = useTranslations$1();
+    t("valid_id-1", void 0, void 0, "Valid");
+    t("fromConstant", void 0, void 0, "Constant");
+    t("-RhnH-", void 0, void 0, "Empty");
+    t("vdaySH", void 0, void 0, "Whitespace");
+    t("9-Ddtu", void 0, void 0, "Next");
+    t("9-Ddtu", void 0, void 0, "Next");
+    t("DXmeDy", void 0, void 0, "Dynamic");
+    t("hTKOdy", void 0, void 0, "Shorthand");
+    return t$1.rich("iSM-et", void 0, void 0, "All rights reserved");
+}
@@ -0,0 +1,11 @@
+import {useExtracted} from 'next-intl';
+
+function Component() {
+  const t = useExtracted();
+  t('Open');
+  t({message: 'Open', description: 'Opens a file'});
+  t({message: 'Open', description: 'The store is open'});
+
+  // An explicit context takes precedence
+  t({message: 'Open', description: 'Opens a file', context: 'file'});
+}
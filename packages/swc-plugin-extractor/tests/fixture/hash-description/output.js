@@ -0,0 +1,9 @@
+import { useTranslations as useTranslations$1 } from 'next-intl';
+function Component() {
+    const t = useTranslations$1();
+    t("JfG49w", void 0, void 0, "Open");
+    t("f3ceKO", void 0, void 0, "Open");
+    t("TJ8wDV", void 0, void 0, "Open");
+    // An explicit context takes precedence
+    t("nWoCia", void 0, void 0, "Open");
+}
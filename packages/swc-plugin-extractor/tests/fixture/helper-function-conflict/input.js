@@ -0,0 +1,15 @@
+import {useExtracted} from 'next-intl';
+
+function getLabel(t) {
+  return t('Label');
+}
+
+function Header() {
+  const t = useExtracted('Header');
+  return getLabel(t);
+}
+
+function Footer() {
+  const t = useExtracted('Footer');
+  return getLabel(t);
+}
@@ -0,0 +1,12 @@
+import { useTranslations as useTranslations$1 } from 'next-intl';
+function getLabel(t) {
+    return t("753yX5", void 0, void 0, "Label");
+}
+function Header() {
+    const t = useTranslations$1('Header');
+    return getLabel(t);
+}
+function Footer() {
+    const t = useTranslations$1('Footer');
+    return getLabel(t);
+}
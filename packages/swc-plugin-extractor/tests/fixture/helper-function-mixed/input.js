@@ -0,0 +1,15 @@
+import {useExtracted} from 'next-intl';
+
+function renderRow(format, item) {
+  return format('Row {name}', {name: item.name});
+}
+
+export function Table({items}) {
+  const t = useExtracted();
+  return items.map((item) => renderRow(t, item));
+}
+
+export function Export({items}) {
+  const fmt = (message, values) => message.replace('{name}', values.name);
+  return items.map((item) => renderRow(fmt, item));
+}
@@ -0,0 +1,14 @@
+import { useTranslations as useTranslations$1 } from 'next-intl';
+function renderRow(format, item) {
+    return format("ZXL9mL", {
+        name: item.name
+    }, void 0, "Row {name}");
+}
+export function Table({ items }) {
+    const t = useTranslations$1();
+    return items.map((item)=>renderRow(t, item));
+}
+export function Export({ items }) {
+    const fmt = (message, values)=>message.replace('{name}', values.name);
+    return items.map((item)=>renderRow(fmt, item));
+}
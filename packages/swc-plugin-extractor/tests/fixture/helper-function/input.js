@@ -0,0 +1,63 @@
+import {getExtracted} from 'next-intl/server';
+import {Extracted, useExtracted} from 'next-intl';
+
+function getName(t) {
+  return t('Anonymous');
+}
+
+function renderRow(t, item) {
+  return (
+    <tr>
+      <td>{t('Row {index}', {index: item.index})}</td>
+      <td>{getName(t)}</td>
+      <td>{renderActions(t)}</td>
+    </tr>
+  );
+}
+
+export default function Table({items}) {
+  const t = useExtracted('Table');
+  return (
+    <table>
+      <caption>{t('Overview')}</caption>
+      {items.map((item) => renderRow(t, item))}
+    </table>
+  );
+}
+
+const renderActions = (t) => <button>{t.rich('<b>Edit</b>', {b: (chunks) => <b>{chunks}</b>})}</button>;
+
+function format(value) {
+  return value.toString();
+}
+format(Table);
+
+function unused(t) {
+  t('Should not be transformed');
+}
+
+export function List({items}) {
+  const t = useExtracted('List');
+
+  // Not deferred since it doesn't receive a translator
+  function renderItem(item) {
+    return <li>{t('Item {name}', {name: item.name})}</li>;
+  }
+
+  return <ul>{items.map((item) => renderItem(item))}<li>{t('Last item')}</li></ul>;
+}
+
+class Report {
+  async load() {
+    this.t = await getExtracted('Report');
+    const renderTitle = (t) => [t('Title'), this.t('Subtitle')];
+    const translate = this.t;
+    return renderTitle(translate);
+  }
+}
+
+export function Summary({total}) {
+  const t = useExtracted('Summary');
+  const renderTotal = (translate) => <p>{translate('Total')} <Extracted>Sum: {total}</Extracted></p>;
+  return renderTotal(t);
+}
@@ -0,0 +1,57 @@
+import { getTranslations as getTranslations$1 } from 'next-intl/server';
+import { useTranslations as useTranslations$1 } from 'next-intl';
+function getName(t) {
+    return t("LXxsbk", void 0, void 0, "Anonymous");
+}
+function renderRow(t, item) {
+    return <tr>
+      <td>{t("QooqKs", {
+        index: item.index
+    }, void 0, "Row {index}")}</td>
+      <td>{getName(t)}</td>
+      <td>{renderActions(t)}</td>
+    </tr>;
+}
+export default function Table({ items }) {
+    const t = useTranslations$1('Table');
+    return <table>
+      <caption>{t("9uOFF3", void 0, void 0, "Overview")}</caption>
+      {items.map((item)=>renderRow(t, item))}
+    </table>;
+}
+const renderActions = (t)=><button>{t.rich("lQsfEx", {
+        b: (chunks)=><b>{chunks}</b>
+    }, void 0, "<b>Edit</b>")}</button>;
+function format(value) {
+    return value.toString();
+}
+format(Table);
+function unused(t) {
+    t('Should not be transformed');
+}
+export function List({ items }) {
+    const t = useTranslations$1('List');
+    // Not deferred since it doesn't receive a translator
+    function renderItem(item) {
+        return <li>{t("zVHtbg", {
+            name: item.name
+        }, void 0, "Item {name}")}</li>;
+    }
+    return <ul>{items.map((item)=>renderItem(item))}<li>{t("bfiwsj", void 0, void 0, "Last item")}</li></ul>;
+}
+class Report {
+    async load() {
+        this.t = await getTranslations$1('Report');
+        const renderTitle = (t)=>[
+                t("9a9-ww", void 0, void 0, "Title"),
+                this.t("DJvCG_", void 0, void 0, "Subtitle")
+            ];
+        const translate = this.t;
+        return renderTitle(translate);
+    }
+}
+export function Summary({ total }) {
+    const t = useTranslations$1('Summary');
+    const renderTotal = (translate)=><p>{translate("MJ2jZQ", void 0, void 0, "Total")} <Extracted>Sum: {total}</Extracted></p>;
+    return renderTotal(t);
+}
@@ -0,0 +1,21 @@
+import {useExtracted} from 'next-intl';
+import {getExtracted} from 'next-intl/server';
+
+async function loadT() {
+  return getExtracted('Report');
+}
+
+const useCheckout = () => useExtracted('Checkout');
+
+export async function Report() {
+  const t = await loadT();
+  t('Title');
+
+  (await getExtracted())('Inline');
+  console.log(getExtracted('Other'));
+}
+
+export function Checkout() {
+  const t = useCheckout();
+  return t('Pay');
+}
@@ -0,0 +1,16 @@
+import { useTranslations as useTranslations$1 } from 'next-intl';
+import { getTranslations as getTranslations$1 } from 'next-intl/server';
+async function loadT() {
+    return getTranslations$1('Report');
+}
+const useCheckout = ()=>useTranslations$1('Checkout');
+export async function Report() {
+    const t = await loadT();
+    t("9a9-ww", void 0, void 0, "Title");
+    (await getTranslations$1())('Inline');
+    console.log(getTranslations$1('Other'));
+}
+export function Checkout() {
+    const t = useCheckout();
+    return t("lD3-8a", void 0, void 0, "Pay");
+}
@@ -0,0 +1,36 @@
+import {Extracted, msg, useExtracted} from 'next-intl';
+
+const greeting = msg('Hello {name');
+
+function Component() {
+  const t = useExtracted();
+
+  // Valid
+  t('You have {count, plural, =0 {no items} one {# item} other {# items}}.');
+  t('{gender, select, female {She} male {He} other {They}} replied');
+  t('Total: {price, number, ::currency/EUR}');
+  t("It's {time, time, short} at '{'home'}'");
+  t.rich('Read the <link>guide</link> and <b>{count, plural, offset:1 other {# more}}</b>');
+  t('Line<br/>break and 1 < 2');
+
+  // Invalid
+  t('You have {count, plural, one {# item} other {# items}');
+  t('Hello {}');
+  t('Hello {first name}');
+  t('{count, plurl, other {#}}');
+  t('{count, plural, one {# item}}');
+  t('{count, plural, one {# item} one {# items} other {#}}');
+  t('{count, plural, =zero {none} other {#}}');
+  t('{gender, select, other {They}');
+  t('{price, number, }');
+  t.rich('Read the <link>guide');
+  t.rich('Read the <link>guide</b>');
+  t.rich('Read the guide</link>');
+  t.rich('Read the <link href>guide</link>');
+  t(`Hello {name`);
+  t('Café {name');
+  t({message: 'Hello {name'});
+  t('Hello\n{name');
+
+  return <Extracted>Hello {'{'}</Extracted>;
+}
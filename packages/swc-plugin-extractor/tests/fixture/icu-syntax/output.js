@@ -0,0 +1,35 @@
+import { useTranslations as useTranslations$1 } from 'next-intl';
+const greeting = {
+    id: "4gExoA",
+    message: "Hello {name"
+};
+function Component() {
+    const t$1 = useTranslations$1();
+    const t = useTranslations$1();
+    // Valid
+    t("S3-orF", void 0, void 0, "You have {count, plural, =0 {no items} one {# item} other {# items}}.");
+    t("ItHkFv", void 0, void 0, "{gender, select, female {She} male {He} other {They}} replied");
+    t("OKAD7p", void 0, void 0, "Total: {price, number, ::currency/EUR}");
+    t("Q0GhhP", void 0, void 0, "It's {time, time, short} at '{'home'}'");
+    t.rich("y8TpQL", void 0, void 0, "Read the <link>guide</link> and <b>{count, plural, offset:1 other {# more}}</b>");
+    t("B4CGJr", void 0, void 0, "Line<br/>break and 1 < 2");
+    // Invalid
+    t("J457CK", void 0, void 0, "You have {count, plural, one {# item} other {# items}");
+    t("JxrgzP", void 0, void 0, "Hello {}");
+    t("e57szv", void 0, void 0, "Hello {first name}");
+    t("-9Jcm5", void 0, void 0, "{count, plurl, other {#}}");
+    t("TPqczt", void 0, void 0, "{count, plural, one {# item}}");
+    t("r7QVLt", void 0, void 0, "{count, plural, one {# item} one {# items} other {#}}");
+    t("OyLLnk", void 0, void 0, "{count, plural, =zero {none} other {#}}");
+    t("O0Ohdq", void 0, void 0, "{gender, select, other {They}");
+    t("OPswxM", void 0, void 0, "{price, number, }");
+    t.rich("MkpgjO", void 0, void 0, "Read the <link>guide");
+    t.rich("qL7iFK", void 0, void 0, "Read the <link>guide</b>");
+    t.rich("d1x7kT", void 0, void 0, "Read the guide</link>");
+    t.rich("p8KoIE", void 0, void 0, "Read the <link href>guide</link>");
+    t("4gExoA", void 0, void 0, "Hello {name");
+    t("6vS7ur", void 0, void 0, "Café {name");
+    t("4gExoA", void 0, void 0, "Hello {name");
+    t("WGo4ID", void 0, void 0, "Hello\n{name");
+    return t$1.rich("MNRWqe", void 0, void 0, "Hello '{'");
+}
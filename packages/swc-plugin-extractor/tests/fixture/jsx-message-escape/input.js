@@ -0,0 +1,12 @@
+import {Extracted} from 'next-intl';
+
+export function Profile({name}) {
+  return (
+    <>
+      <Extracted>Jane'{name}' is here</Extracted>
+      <Extracted>Use {'{'}{'{'}name{'}'}{'}'} for placeholders</Extracted>
+      <Extracted>Quoted {"'{'"} and {'<'}b{">"}</Extracted>
+      <Extracted>It's {name}'s turn</Extracted>
+    </>
+  );
+}
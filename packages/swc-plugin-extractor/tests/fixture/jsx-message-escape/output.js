@@ -0,0 +1,14 @@
+import { useTranslations as useTranslations$1 } from "next-intl";
+export function Profile({ name }) {
+    const t$1 = useTranslations$1();
+    return <>
+      {t$1.rich("6WAbSL", {
+        name
+    }, void 0, "Jane''{name}'' is here")}
+      {t$1.rich("wb3rjs", void 0, void 0, "Use '{{'name'}}' for placeholders")}
+      {t$1.rich("Vfny4p", void 0, void 0, "Quoted '''{''' and '<'b>")}
+      {t$1.rich("zZeQj8", {
+        name
+    }, void 0, "It''s {name}''s turn")}
+    </>;
+}
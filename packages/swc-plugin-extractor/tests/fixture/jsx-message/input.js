@@ -0,0 +1,47 @@
+import {Extracted} from 'next-intl';
+import Link from 'next/link';
+
+export default function Profile({name, count}) {
+  return (
+    <div>
+      <Extracted description="Greeting in the header">
+        Hello <b>{name}</b>,
+        welcome back!
+      </Extracted>
+      <p>
+        <Extracted id="terms">
+          Read the <Link href="/terms">terms</Link> and <Link href="/privacy">privacy policy</Link>.<br />
+          You have {count} new {'{'}messages{'}'}.
+        </Extracted>
+      </p>
+    </div>
+  );
+}
+
+export const Footer = () => <Extracted>Made with love</Extracted>;
+
+export async function Page({user}) {
+  const {name} = user;
+  return <h1><Extracted>Hi {name}</Extracted></h1>;
+}
+
+export function Invalid({user}) {
+  return <Extracted>Hi {user.name}</Extracted>;
+}
+
+export const Card = memo(({title}) => <h2><Extracted>Card {title}</Extracted></h2>);
+
+export async function save() {
+  'use server';
+  return <Extracted>Saved</Extracted>;
+}
+
+function renderRow(row) {
+  return <td><Extracted>Row</Extracted></td>;
+}
+
+class Legacy extends Component {
+  render() {
+    return <Extracted>Legacy</Extracted>;
+  }
+}
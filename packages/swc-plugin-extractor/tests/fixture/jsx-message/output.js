@@ -0,0 +1,53 @@
+import { useTranslations as useTranslations$1 } from "next-intl";
+import { getTranslations as getTranslations$1 } from "next-intl/server";
+import Link from 'next/link';
+export default function Profile({ name, count }) {
+    const t$1 = useTranslations$1();
+    return <div>
+      {t$1.rich("FC3bvJ", {
+        name,
+        b: (chunks)=><b>{chunks}</b>
+    }, void 0, "Hello <b>{name}</b>, welcome back!")}
+      <p>
+        {t$1.rich("terms", {
+        Link: (chunks)=><Link href="/terms">{chunks}</Link>,
+        Link2: (chunks)=><Link href="/privacy">{chunks}</Link>,
+        br: <br/>,
+        count
+    }, void 0, "Read the <Link>terms</Link> and <Link2>privacy policy</Link2>.{br}You have {count} new '{'messages'}'.")}
+      </p>
+    </div>;
+}
+export const Footer = ()=>{
+    const t$2 = useTranslations$1();
+    return t$2.rich("mdGes7", void 0, void 0, "Made with love");
+};
+export async function Page({ user }) {
+    const t$3 = await getTranslations$1();
+    const { name } = user;
+    return <h1>{t$3.rich("1iUHR3", {
+        name
+    }, void 0, "Hi {name}")}</h1>;
+}
+export function Invalid({ user }) {
+    return <Extracted>Hi {user.name}</Extracted>;
+}
+export const Card = memo(({ title })=>{
+    const t$4 = useTranslations$1();
+    return <h2>{t$4.rich("5tBr9r", {
+        title
+    }, void 0, "Card {title}")}</h2>;
+});
+export async function save() {
+    'use server';
+    const t$5 = await getTranslations$1();
+    return t$5.rich("fsB_4p", void 0, void 0, "Saved");
+}
+function renderRow(row) {
+    return <td><Extracted>Row</Extracted></td>;
+}
+class Legacy extends Component {
+    render() {
+        return <Extracted>Legacy</Extracted>;
+    }
+}
@@ -0,0 +1,17 @@
+import {useExtracted} from 'next-intl';
+
+function Component() {
+  const t = useExtracted('Cart');
+
+  // Fixed typo of a previously extracted message
+  t(/* keep */ 'Continue shopping');
+
+  // Unchanged since the key was kept
+  t(/* keep */ 'Checkout');
+
+  // Only messages with the same context are considered
+  t({message: /* keep */ 'Opened', context: 'door'});
+
+  // Messages of other namespaces aren't considered
+  t(/* keep */ 'Remove!');
+}
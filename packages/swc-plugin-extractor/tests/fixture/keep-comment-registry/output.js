@@ -0,0 +1,12 @@
+import { useTranslations as useTranslations$1 } from 'next-intl';
+function Component() {
+    const t = useTranslations$1('Cart');
+    // Fixed typo of a previously extracted message
+    t(/* keep */ "4sLqtW", void 0, void 0, "Continue shopping");
+    // Unchanged since the key was kept
+    t(/* keep */ "mW0fQ2", void 0, void 0, "Checkout");
+    // Only messages with the same context are considered
+    t("9hBvKe", void 0, void 0, "Opened");
+    // Messages of other namespaces aren't considered
+    t(/* keep */ "YcxoGp", void 0, void 0, "Remove!");
+}
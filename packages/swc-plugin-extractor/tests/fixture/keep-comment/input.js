@@ -0,0 +1,11 @@
+import {useExtracted} from 'next-intl';
+
+function Component() {
+  const t = useExtracted('Cart');
+  t(/* keep: Your cart is emtpy */ 'Your cart is empty');
+  t(/* keep-id: 7cXnzz */ 'Proceed to checkout');
+  t(/* keep-id: cart.empty */ 'Nothing here');
+  t({message: /* keep: Remove item */ 'Remove this item'});
+  t(/* keep */ 'Continue');
+  t({id: 'total', message: /* keep: Totl */ 'Total'});
+}
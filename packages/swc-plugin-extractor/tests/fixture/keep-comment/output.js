@@ -0,0 +1,10 @@
+import { useTranslations as useTranslations$1 } from 'next-intl';
+function Component() {
+    const t = useTranslations$1('Cart');
+    t(/* keep: Your cart is emtpy */ "matZdp", void 0, void 0, "Your cart is empty");
+    t(/* keep-id: 7cXnzz */ "7cXnzz", void 0, void 0, "Proceed to checkout");
+    t(/* keep-id: cart.empty */ "4WrgwI", void 0, void 0, "Nothing here");
+    t("3dwSlf", void 0, void 0, "Remove this item");
+    t(/* keep */ "acrOoz", void 0, void 0, "Continue");
+    t("total", void 0, void 0, "Total");
+}
@@ -0,0 +1,9 @@
+import {useExtracted} from 'next-intl';
+
+function Component() {
+  const t = useExtracted();
+  t('Done');
+  t('Open');
+  t('Undo');
+  t('Open');
+}
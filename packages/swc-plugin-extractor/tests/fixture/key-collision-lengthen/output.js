@@ -0,0 +1,8 @@
+import { useTranslations as useTranslations$1 } from 'next-intl';
+function Component() {
+    const t = useTranslations$1();
+    t("J", void 0, void 0, "Done");
+    t("Jf", void 0, void 0, "Open");
+    t("Jk", void 0, void 0, "Undo");
+    t("Jf", void 0, void 0, "Open");
+}
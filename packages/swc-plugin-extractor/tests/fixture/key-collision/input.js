@@ -0,0 +1,14 @@
+import {useExtracted} from 'next-intl';
+
+function Component() {
+  const t = useExtracted();
+  t('Cancel');
+  t('Copy');
+  t('Cancel');
+  t({id: 'explicit', message: 'Explicit IDs are not checked'});
+
+  // Keys of different namespaces don't collide
+  const tForm = useExtracted('Form');
+  t('Edit');
+  tForm('Submit');
+}
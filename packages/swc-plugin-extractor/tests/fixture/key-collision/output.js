@@ -0,0 +1,12 @@
+import { useTranslations as useTranslations$1 } from 'next-intl';
+function Component() {
+    const t = useTranslations$1();
+    t("4", void 0, void 0, "Cancel");
+    t("4", void 0, void 0, "Copy");
+    t("4", void 0, void 0, "Cancel");
+    t("explicit", void 0, void 0, "Explicit IDs are not checked");
+    // Keys of different namespaces don't collide
+    const tForm = useTranslations$1('Form');
+    t("w", void 0, void 0, "Edit");
+    tForm("w", void 0, void 0, "Submit");
+}
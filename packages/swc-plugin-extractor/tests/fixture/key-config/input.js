@@ -0,0 +1,7 @@
+import {useExtracted} from 'next-intl';
+
+function Component() {
+  const t = useExtracted();
+  t('Hello there!');
+  t(/* keep: Helo there! */ 'Hello there, welcome!');
+}
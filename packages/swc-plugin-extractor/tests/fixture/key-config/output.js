@@ -0,0 +1,6 @@
+import { useTranslations as useTranslations$1 } from 'next-intl';
+function Component() {
+    const t = useTranslations$1();
+    t("app_ibi45IZCFG", void 0, void 0, "Hello there!");
+    t(/* keep: Helo there! */ "app_HSZ0_Yyao_", void 0, void 0, "Hello there, welcome!");
+}
@@ -0,0 +1,6 @@
+import {useExtracted} from 'next-intl';
+
+function Component() {
+  const t = useExtracted();
+  t('Hello there!');
+}
@@ -0,0 +1,14 @@
+import {useExtracted} from 'next-intl';
+
+function Component() {
+  const t = useExtracted();
+
+  // Collides with a message of another file
+  t('Edit');
+
+  // Replaces a message that was previously extracted from this file
+  t('Done');
+
+  // Same message as in another file
+  t('Save');
+}
@@ -0,0 +1,10 @@
+import { useTranslations as useTranslations$1 } from 'next-intl';
+function Component() {
+    const t = useTranslations$1();
+    // Collides with a message of another file
+    t("w", void 0, void 0, "Edit");
+    // Replaces a message that was previously extracted from this file
+    t("J", void 0, void 0, "Done");
+    // Same message as in another file
+    t("j", void 0, void 0, "Save");
+}
@@ -0,0 +1,9 @@
+import {useExtracted} from 'next-intl';
+
+function Component() {
+  const t = useExtracted();
+  t('Checkout total');
+  t('Hello {name}! You have {count} new messages.', {name: 'Jane', count: 3});
+  t('¿Qué tal?');
+  t({id: 'explicit', message: 'Explicit IDs are kept'});
+}
@@ -0,0 +1,11 @@
+import { useTranslations as useTranslations$1 } from 'next-intl';
+function Component() {
+    const t = useTranslations$1();
+    t("checkout_total_37a0", void 0, void 0, "Checkout total");
+    t("hello_name_you_have_84b7", {
+        name: 'Jane',
+        count: 3
+    }, void 0, "Hello {name}! You have {count} new messages.");
+    t("qué_tal_9a5d", void 0, void 0, "¿Qué tal?");
+    t("explicit", void 0, void 0, "Explicit IDs are kept");
+}
@@ -0,0 +1,14 @@
+import {useExtracted} from 'next-intl';
+import {user} from './data';
+import {LABEL} from './constants';
+
+export function Profile() {
+  const t = useExtracted();
+  const tProfile = useExtracted('Profile');
+  return (
+    <>
+      {t(user.name)}
+      {tProfile(LABEL)}
+    </>
+  );
+}
@@ -0,0 +1,11 @@
+import { useTranslations as useTranslations$1 } from 'next-intl';
+import { user } from './data';
+import { LABEL } from './constants';
+export function Profile() {
+    const t = useTranslations$1();
+    const tProfile = useTranslations$1('Profile');
+    return <>
+      {t(user.name)}
+      {tProfile(LABEL)}
+    </>;
+}
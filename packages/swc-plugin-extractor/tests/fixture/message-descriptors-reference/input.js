@@ -0,0 +1,7 @@
+import {msg, defineMessages} from 'next-intl';
+
+const label = msg('Home');
+const createLabel = msg;
+const labels = ['About us', 'Contact'].map(msg);
+
+export {defineMessages};
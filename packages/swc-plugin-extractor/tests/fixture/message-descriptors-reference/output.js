@@ -0,0 +1,10 @@
+const label = {
+    id: "ejEGdx",
+    message: "Home"
+};
+const createLabel = msg;
+const labels = [
+    'About us',
+    'Contact'
+].map(msg);
+export { defineMessages };
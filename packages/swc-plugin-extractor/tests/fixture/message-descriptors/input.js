@@ -0,0 +1,39 @@
+import {msg, defineMessages, useExtracted} from 'next-intl';
+
+const PENDING_LABEL = 'Pending';
+
+export const statusLabels = defineMessages({
+  pending: PENDING_LABEL,
+  shipped: 'Shipped',
+  delivered: `Delivered`
+});
+
+const navItems = [
+  {href: '/', label: msg('Home')},
+  {href: '/about', label: msg('About us')}
+];
+
+export function OrderStatus({status}) {
+  const t = useExtracted();
+  return <span title={t.has(statusLabels[status]) ? 'yes' : 'no'}>{t(statusLabels[status])}</span>;
+}
+
+export function Nav() {
+  const t = useExtracted();
+  const tNav = useExtracted('Nav');
+  return navItems.map((item) => <a href={item.href}>{t(item.label)} {tNav(statusLabels.pending)}</a>);
+}
+
+export function Footer() {
+  const t = useExtracted();
+  const links = [];
+  for (const {label} of Object.values(footerLabels)) {
+    links.push(<span>{t(label)}</span>);
+  }
+  return <footer>{t(footerLabels.copyright)}{links}</footer>;
+}
+
+const footerLabels = defineMessages({
+  copyright: '© Example',
+  imprint: 'Imprint'
+});
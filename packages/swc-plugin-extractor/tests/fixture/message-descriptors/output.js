@@ -0,0 +1,59 @@
+import { useTranslations as useTranslations$1 } from 'next-intl';
+const PENDING_LABEL = 'Pending';
+export const statusLabels = {
+    pending: {
+        id: "eKEL_g",
+        message: "Pending"
+    },
+    shipped: {
+        id: "OtIOMb",
+        message: "Shipped"
+    },
+    delivered: {
+        id: "DMqnF4",
+        message: "Delivered"
+    }
+};
+const navItems = [
+    {
+        href: '/',
+        label: {
+            id: "ejEGdx",
+            message: "Home"
+        }
+    },
+    {
+        href: '/about',
+        label: {
+            id: "wGhqrf",
+            message: "About us"
+        }
+    }
+];
+export function OrderStatus({ status }) {
+    const t = useTranslations$1();
+    return <span title={t.has(statusLabels[status].id) ? 'yes' : 'no'}>{t(statusLabels[status].id, void 0, void 0, statusLabels[status].message)}</span>;
+}
+export function Nav() {
+    const t = useTranslations$1();
+    const tNav = useTranslations$1('Nav');
+    return navItems.map((item)=><a href={item.href}>{t(item.label.id, void 0, void 0, item.label.message)} {tNav(statusLabels.pending)}</a>);
+}
+export function Footer() {
+    const t = useTranslations$1();
+    const links = [];
+    for (const { label } of Object.values(footerLabels)){
+        links.push(<span>{t(label.id, void 0, void 0, label.message)}</span>);
+    }
+    return <footer>{t(footerLabels.copyright.id, void 0, void 0, footerLabels.copyright.message)}{links}</footer>;
+}
+const footerLabels = {
+    copyright: {
+        id: "O11Ofj",
+        message: "© Example"
+    },
+    imprint: {
+        id: "xYJkOZ",
+        message: "Imprint"
+    }
+};
@@ -0,0 +1,37 @@
+import {Extracted, useExtracted} from 'next-intl';
+
+function Component({name, count, values, isAdmin}) {
+  const t = useExtracted();
+
+  // Valid
+  t('Hello {name}!', {name});
+  t('You have {count, plural, one {# item} other {# items}}.', {count});
+  t.rich('Read the <link>guide</link>', {link: (chunks) => chunks});
+  t.rich('Read the <b>docs</b>', {b(chunks) {return chunks;}});
+  t({message: 'Hi {name}', values: {name}});
+  t(isAdmin ? 'Hello {name}, admin' : 'Hello {name}', {name});
+  t.has('Hello {name}!');
+
+  // Skipped
+  t('Hello {name}!', values);
+  t('Hello {name}!', {...values});
+  t('Hello {name}!', ...values);
+  t('Hello {name', {name, unused: 1});
+
+  // Missing values
+  t('Hello {name}!');
+  t('Hello {name}, you are {name}!', {});
+  t.rich('Read the <link>guide</link>');
+  t(isAdmin ? 'Hello {name}, admin' : 'Hello', {});
+
+  // Unused values
+  t('Hello!', {name});
+  t('Hello {name}!', {name, count: 2});
+
+  // Strings passed to numbers
+  t('You have {count, plural, other {# items}}.', {count: '2'});
+  t('Total: {price, number}', {price: `10`});
+  t('{place, selectordinal, other {#th}}', {place: "1"});
+
+  return <Extracted>Hello</Extracted>;
+}
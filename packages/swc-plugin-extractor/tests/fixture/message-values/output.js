@@ -0,0 +1,83 @@
+import { useTranslations as useTranslations$1 } from 'next-intl';
+function Component({ name, count, values, isAdmin }) {
+    const t$1 = useTranslations$1();
+    const t = useTranslations$1();
+    // Valid
+    t("wafoOY", {
+        name
+    }, void 0, "Hello {name}!");
+    t("u60JrV", {
+        count
+    }, void 0, "You have {count, plural, one {# item} other {# items}}.");
+    t.rich("n4Pfsu", {
+        link: (chunks)=>chunks
+    }, void 0, "Read the <link>guide</link>");
+    t.rich("q2vyX_", {
+        b (chunks) {
+            return chunks;
+        }
+    }, void 0, "Read the <b>docs</b>");
+    t("1iUHR3", {
+        name
+    }, void 0, "Hi {name}");
+    t(...isAdmin ? [
+        "wNyo8n",
+        {
+            name
+        },
+        void 0,
+        "Hello {name}, admin"
+    ] : [
+        "QM7ITA",
+        {
+            name
+        },
+        void 0,
+        "Hello {name}"
+    ]);
+    t.has("wafoOY");
+    // Skipped
+    t("wafoOY", values, void 0, "Hello {name}!");
+    t("wafoOY", {
+        ...values
+    }, void 0, "Hello {name}!");
+    t("wafoOY", ...values, void 0, "Hello {name}!");
+    t("4gExoA", {
+        name,
+        unused: 1
+    }, void 0, "Hello {name");
+    // Missing values
+    t("wafoOY", void 0, void 0, "Hello {name}!");
+    t("aVmTSG", {}, void 0, "Hello {name}, you are {name}!");
+    t.rich("n4Pfsu", void 0, void 0, "Read the <link>guide</link>");
+    t(...isAdmin ? [
+        "wNyo8n",
+        {},
+        void 0,
+        "Hello {name}, admin"
+    ] : [
+        "NhX4DJ",
+        {},
+        void 0,
+        "Hello"
+    ]);
+    // Unused values
+    t("OpKKos", {
+        name
+    }, void 0, "Hello!");
+    t("wafoOY", {
+        name,
+        count: 2
+    }, void 0, "Hello {name}!");
+    // Strings passed to numbers
+    t("1IIl40", {
+        count: '2'
+    }, void 0, "You have {count, plural, other {# items}}.");
+    t("xA9xsv", {
+        price: `10`
+    }, void 0, "Total: {price, number}");
+    t("6AJvmD", {
+        place: "1"
+    }, void 0, "{place, selectordinal, other {#th}}");
+    return t$1.rich("NhX4DJ", void 0, void 0, "Hello");
+}
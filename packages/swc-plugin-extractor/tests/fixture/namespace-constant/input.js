@@ -0,0 +1,35 @@
+import {useExtracted} from 'next-intl';
+import {getExtracted} from 'next-intl/server';
+
+const NS = 'Checkout';
+const FEATURE = 'Billing';
+
+function Checkout() {
+  const t = useExtracted(NS);
+  t('Pay now');
+}
+
+function Form() {
+  const t = useExtracted(`${FEATURE}.Form`);
+  t('Submit');
+}
+
+async function Invoice({locale}) {
+  const t = await getExtracted({locale, namespace: 'Invoice.' + NS});
+  t('Download');
+}
+
+function Dynamic({section}) {
+  const t = useExtracted(section);
+  t('Unscoped');
+}
+
+function Default() {
+  const t = useExtracted(undefined);
+  t('Without namespace');
+}
+
+async function Options({options}) {
+  const t = await getExtracted({locale: options.locale});
+  t('Without namespace');
+}
@@ -0,0 +1,33 @@
+import { useTranslations as useTranslations$1 } from 'next-intl';
+import { getTranslations as getTranslations$1 } from 'next-intl/server';
+const NS = 'Checkout';
+const FEATURE = 'Billing';
+function Checkout() {
+    const t = useTranslations$1(NS);
+    t("7tOz-m", void 0, void 0, "Pay now");
+}
+function Form() {
+    const t = useTranslations$1(`${FEATURE}.Form`);
+    t("wSZR47", void 0, void 0, "Submit");
+}
+async function Invoice({ locale }) {
+    const t = await getTranslations$1({
+        locale,
+        namespace: 'Invoice.' + NS
+    });
+    t("5q3qC0", void 0, void 0, "Download");
+}
+function Dynamic({ section }) {
+    const t = useTranslations$1(section);
+    t("qsdVls", void 0, void 0, "Unscoped");
+}
+function Default() {
+    const t = useTranslations$1(undefined);
+    t("wZdHMI", void 0, void 0, "Without namespace");
+}
+async function Options({ options }) {
+    const t = await getTranslations$1({
+        locale: options.locale
+    });
+    t("wZdHMI", void 0, void 0, "Without namespace");
+}
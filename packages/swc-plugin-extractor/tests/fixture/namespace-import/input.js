@@ -0,0 +1,25 @@
+import * as intl from 'next-intl';
+import server from 'next-intl/server';
+import * as React from 'react';
+
+function Component() {
+  const t = intl.useExtracted('ui');
+  const format = intl.useFormatter();
+  t('Hello!');
+}
+
+async function AsyncComponent() {
+  const t = await server.getExtracted();
+  t('Hey!');
+}
+
+function useLabels(react) {
+  react.useDebugValue('labels');
+  const t = intl.useExtracted('Labels');
+  return {t};
+}
+
+function Labels() {
+  const {t} = useLabels(React);
+  t('Label');
+}
@@ -0,0 +1,23 @@
+import * as intl from 'next-intl';
+import server from 'next-intl/server';
+import * as React from 'react';
+function Component() {
+    const t = intl.useTranslations('ui');
+    const format = intl.useFormatter();
+    t("OpKKos", void 0, void 0, "Hello!");
+}
+async function AsyncComponent() {
+    const t = await server.getTranslations();
+    t("-YJVTi", void 0, void 0, "Hey!");
+}
+function useLabels(react) {
+    react.useDebugValue('labels');
+    const t = intl.useTranslations('Labels');
+    return {
+        t
+    };
+}
+function Labels() {
+    const { t } = useLabels(React);
+    t("753yX5", void 0, void 0, "Label");
+}
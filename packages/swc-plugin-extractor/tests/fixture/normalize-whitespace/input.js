@@ -0,0 +1,11 @@
+import {useExtracted} from 'next-intl';
+
+function Component() {
+  const t = useExtracted();
+  t(`
+    Welcome back!
+      You have new messages.
+  `);
+  t('  Trailing whitespace  ');
+  t('Trailing whitespace');
+}
@@ -0,0 +1,7 @@
+import { useTranslations as useTranslations$1 } from 'next-intl';
+function Component() {
+    const t = useTranslations$1();
+    t("4tWR3Y", void 0, void 0, "Welcome back!\n  You have new messages.");
+    t("L_P_sw", void 0, void 0, "Trailing whitespace");
+    t("L_P_sw", void 0, void 0, "Trailing whitespace");
+}
@@ -0,0 +1,17 @@
+import {useExtracted} from 'next-intl';
+
+function Component() {
+  const t = useExtracted();
+
+  // Precomposed and combining accent share a key
+  t('Café');
+  t('Cafe\u0301');
+  t('Caf\u00e9');
+
+  // Line breaks share a key
+  t('First line\nSecond line');
+  t('First line\r\nSecond line');
+
+  t('Unpaired \uD83D');
+  t('Paired 😀');
+}
@@ -0,0 +1,13 @@
+import { useTranslations as useTranslations$1 } from 'next-intl';
+function Component() {
+    const t = useTranslations$1();
+    // Precomposed and combining accent share a key
+    t("-EBHXe", void 0, void 0, "Café");
+    t("-EBHXe", void 0, void 0, "Café");
+    t("-EBHXe", void 0, void 0, "Café");
+    // Line breaks share a key
+    t("Pr5UPr", void 0, void 0, "First line\nSecond line");
+    t("Pr5UPr", void 0, void 0, "First line\nSecond line");
+    t("_GxXb3", void 0, void 0, "Unpaired �");
+    t("6cStZN", void 0, void 0, "Paired 😀");
+}
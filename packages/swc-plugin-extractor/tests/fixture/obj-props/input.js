@@ -0,0 +1,17 @@
+import {useExtracted} from 'next-intl';
+
+const message = 'Shorthand message';
+const description = 'Shorthand description';
+
+function Component({name, base}) {
+  const t = useExtracted();
+  t({'message': 'String key', "description": 'Quoted'});
+  t({message, description});
+  t({message: 'With values', values: {name}});
+  t({mesage: 'Typo'});
+  t({...base, message: 'Spread'});
+  t({message: 'First', message: 'Second'});
+  t({message() {}});
+  t({[name]: 'Computed'});
+  t({description: 'No message'});
+}
@@ -0,0 +1,25 @@
+import { useTranslations as useTranslations$1 } from 'next-intl';
+const message = 'Shorthand message';
+const description = 'Shorthand description';
+function Component({ name, base }) {
+    const t = useTranslations$1();
+    t("kqVSr6", void 0, void 0, "String key");
+    t("77MP_R", void 0, void 0, "Shorthand message");
+    t("RFK5Ny", {
+        name
+    }, void 0, "With values");
+    t({
+        mesage: 'Typo'
+    });
+    t("B7hDsY", void 0, void 0, "Spread");
+    t("S45aoc", void 0, void 0, "First");
+    t({
+        message () {}
+    });
+    t({
+        [name]: 'Computed'
+    });
+    t({
+        description: 'No message'
+    });
+}
@@ -0,0 +1,61 @@
+import {useExtracted} from 'next-intl';
+import {getExtracted} from 'next-intl/server';
+
+function useLabels() {
+  const t = useExtracted('Labels');
+  const translate = t;
+  translate('Alias');
+
+  const {rich, has: hasMessage} = t;
+  rich('Destructured <b>rich</b>', {b: (chunks) => <b>{chunks}</b>});
+  hasMessage('Destructured has');
+
+  const labels = {t, locale: 'en', format: t.markup};
+  labels.t('Property');
+  labels.t.rich('Property <b>rich</b>', {b: (chunks) => <b>{chunks}</b>});
+  labels.format('Property <b>markup</b>', {b: (chunks) => `<b>${chunks}</b>`});
+
+  const {t: fromBag} = labels;
+  fromBag('Destructured property');
+
+  return labels;
+}
+
+const useGreeting = () => {
+  const t = useExtracted('Greeting');
+  return t;
+};
+
+async function getLabels() {
+  const t = await getExtracted('Server');
+  return {t};
+}
+
+function Labels() {
+  const {t, format} = useLabels();
+  t('From hook');
+  format('From hook <b>markup</b>', {b: (chunks) => `<b>${chunks}</b>`});
+
+  const labels = useLabels();
+  labels.t('From hook property');
+
+  const greet = useGreeting();
+  greet('From hook translator');
+}
+
+async function ServerLabels() {
+  const {t} = await getLabels();
+  t('From async function');
+}
+
+class LegacyComponent extends React.Component {
+  async componentDidMount() {
+    this.t = await getExtracted('Legacy');
+    this.t('Class property');
+    const {markup} = this.t;
+    markup('Class <b>markup</b>', {b: (chunks) => `<b>${chunks}</b>`});
+    [1].forEach(function () {
+      this.t('Unrelated this');
+    });
+  }
+}
@@ -0,0 +1,67 @@
+import { useTranslations as useTranslations$1 } from 'next-intl';
+import { getTranslations as getTranslations$1 } from 'next-intl/server';
+function useLabels() {
+    const t = useTranslations$1('Labels');
+    const translate = t;
+    translate("Ri9jA7", void 0, void 0, "Alias");
+    const { rich, has: hasMessage } = t;
+    rich("Nmf33R", {
+        b: (chunks)=><b>{chunks}</b>
+    }, void 0, "Destructured <b>rich</b>");
+    hasMessage("DhfFqJ");
+    const labels = {
+        t,
+        locale: 'en',
+        format: t.markup
+    };
+    labels.t("-SDMR3", void 0, void 0, "Property");
+    labels.t.rich("qlA7MV", {
+        b: (chunks)=><b>{chunks}</b>
+    }, void 0, "Property <b>rich</b>");
+    labels.format("nvU4vs", {
+        b: (chunks)=>`<b>${chunks}</b>`
+    }, void 0, "Property <b>markup</b>");
+    const { t: fromBag } = labels;
+    fromBag("Gx5cqu", void 0, void 0, "Destructured property");
+    return labels;
+}
+const useGreeting = ()=>{
+    const t = useTranslations$1('Greeting');
+    return t;
+};
+async function getLabels() {
+    const t = await getTranslations$1('Server');
+    return {
+        t
+    };
+}
+function Labels() {
+    const { t, format } = useLabels();
+    t("ktltbH", void 0, void 0, "From hook");
+    format("uO-mJs", {
+        b: (chunks)=>`<b>${chunks}</b>`
+    }, void 0, "From hook <b>markup</b>");
+    const labels = useLabels();
+    labels.t("UB2tzF", void 0, void 0, "From hook property");
+    const greet = useGreeting();
+    greet("DXbHN4", void 0, void 0, "From hook translator");
+}
+async function ServerLabels() {
+    const { t } = await getLabels();
+    t("T1A3Qh", void 0, void 0, "From async function");
+}
+class LegacyComponent extends React.Component {
+    async componentDidMount() {
+        this.t = await getTranslations$1('Legacy');
+        this.t("7Tq6sz", void 0, void 0, "Class property");
+        const { markup } = this.t;
+        markup("XiGY1L", {
+            b: (chunks)=>`<b>${chunks}</b>`
+        }, void 0, "Class <b>markup</b>");
+        [
+            1
+        ].forEach(function() {
+            this.t('Unrelated this');
+        });
+    }
+}
@@ -0,0 +1,14 @@
+import {useExtracted} from 'next-intl';
+import {getExtracted} from 'next-intl/server';
+
+export {useExtracted};
+
+const hooks = {useExtracted, load: getExtracted};
+
+export async function Page() {
+  const [t] = await Promise.all([getExtracted(), ...[getExtracted]]);
+  const translator = useCallback(useExtracted);
+  return t('Hello');
+}
+
+export default getExtracted;
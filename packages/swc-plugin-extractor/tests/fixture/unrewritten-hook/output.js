@@ -0,0 +1,18 @@
+import { useTranslations as useTranslations$1 } from 'next-intl';
+import { getTranslations as getTranslations$1 } from 'next-intl/server';
+export { useExtracted };
+const hooks = {
+    useExtracted,
+    load: getExtracted
+};
+export async function Page() {
+    const [t] = await Promise.all([
+        getTranslations$1(),
+        ...[
+            getExtracted
+        ]
+    ]);
+    const translator = useCallback(useExtracted);
+    return t("NhX4DJ", void 0, void 0, "Hello");
+}
+export default getExtracted;
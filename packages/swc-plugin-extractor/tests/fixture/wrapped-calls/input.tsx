@@ -0,0 +1,14 @@
+import {useExtracted} from 'next-intl';
+
+function Component({user}: {user?: {name: string}}) {
+  const t = useExtracted();
+  (t)('Parenthesized');
+  t!('Non-null');
+  t?.('Optional call');
+  t.rich?.('Optional <b>rich</b>', {b: (chunks) => <b>{chunks}</b>});
+  t['markup']('Computed <b>markup</b>', {b: (chunks) => `<b>${chunks}</b>`});
+  t('Const assertion' as const);
+  t(('Satisfies' satisfies string));
+  t({message: 'Object with cast' as const});
+  t('Hello {name}', {name: user!.name});
+}
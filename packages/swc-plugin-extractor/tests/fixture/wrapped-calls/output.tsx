@@ -0,0 +1,23 @@
+import { useTranslations as useTranslations$1 } from 'next-intl';
+function Component({ user }: {
+    user?: {
+        name: string;
+    };
+}) {
+    const t = useTranslations$1();
+    t("vsbEpl", void 0, void 0, "Parenthesized");
+    t!("tfXYo6", void 0, void 0, "Non-null");
+    t?.("JSUxva", void 0, void 0, "Optional call");
+    t.rich?.("4qfAPE", {
+        b: (chunks)=><b>{chunks}</b>
+    }, void 0, "Optional <b>rich</b>");
+    t['markup']("aJCBXy", {
+        b: (chunks)=>`<b>${chunks}</b>`
+    }, void 0, "Computed <b>markup</b>");
+    t("4ubV3-", void 0, void 0, "Const assertion");
+    t("mDXnNy", void 0, void 0, "Satisfies");
+    t("zuqFAW", void 0, void 0, "Object with cast");
+    t("QM7ITA", {
+        name: user!.name
+    }, void 0, "Hello {name}");
+}
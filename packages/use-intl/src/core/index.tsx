@@ -23,6 +23,11 @@ export type {
 } from './MessageKeys.js';
 export {createIntlFormatters as _createIntlFormatters} from './formatters.js';
 export {createCache as _createCache} from './formatters.js';
+export {
+  msg as _msg,
+  defineMessages as _defineMessages
+} from './messageDescriptors.js';
+export type {MessageDescriptor as _MessageDescriptor} from './messageDescriptors.js';
 export type {default as AppConfig, Locale, Messages} from './AppConfig.js';
 export {default as hasLocale} from './hasLocale.js';
 export type {default as RelativeTimeFormatOptions} from './RelativeTimeFormatOptions.js';
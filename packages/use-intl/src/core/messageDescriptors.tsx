@@ -0,0 +1,28 @@
+/**
+ * A message that is defined outside of a component and
+ * can be passed to the translator returned by `useExtracted`.
+ */
+export type MessageDescriptor<Message extends string = string> = {
+  id?: string;
+  message: Message;
+};
+
+// Note: These APIs are usually compiled into descriptors with an ID, the
+// runtime implementations only serve as a fallback (e.g. in tests).
+
+/** Defines a message outside of a component. */
+export function msg<Message extends string>(
+  /** Inline ICU message in the source locale. */
+  message: Message
+): MessageDescriptor<Message> {
+  return {message};
+}
+
+/** Defines a group of messages outside of a component. */
+export function defineMessages<Messages extends Record<string, string>>(
+  messages: Messages
+): {[Key in keyof Messages]: MessageDescriptor<Messages[Key]>} {
+  return Object.fromEntries(
+    Object.entries(messages).map(([key, message]) => [key, msg(message)])
+  ) as {[Key in keyof Messages]: MessageDescriptor<Messages[Key]>};
+}
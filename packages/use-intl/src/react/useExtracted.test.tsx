@@ -1,6 +1,7 @@
 import {render, screen} from '@testing-library/react';
 import type {ComponentProps, ReactNode} from 'react';
 import {describe, expect, it, vi} from 'vitest';
+import {defineMessages, msg} from '../core/messageDescriptors.js';
 import IntlProvider from './IntlProvider.js';
 import useExtracted from './useExtracted.js';
 
@@ -377,3 +378,63 @@ describe('object form', () => {
     screen.getByText('Hello <b>Jan</b>');
   });
 });
+
+describe('descriptors', () => {
+  it('accepts descriptors from `msg`', () => {
+    const greeting = msg('Hello');
+    function Component() {
+      const t = useExtracted();
+      return t(greeting);
+    }
+    render(
+      <MockProvider>
+        <Component />
+      </MockProvider>
+    );
+    screen.getByText('Hello');
+  });
+
+  it('accepts descriptors from `defineMessages` with values', () => {
+    const labels = defineMessages({
+      greeting: 'Hello {name}'
+    });
+    function Component() {
+      const t = useExtracted();
+      return t(labels.greeting, {name: 'World'});
+    }
+    render(
+      <MockProvider>
+        <Component />
+      </MockProvider>
+    );
+    screen.getByText('Hello World');
+  });
+
+  it('accepts descriptors with rich text', () => {
+    const greeting = msg('Hello <b>Jan</b>');
+    function Component() {
+      const t = useExtracted();
+      return t.rich(greeting, {b: (chunks) => <b>{chunks}</b>});
+    }
+    const {container} = render(
+      <MockProvider>
+        <Component />
+      </MockProvider>
+    );
+    expect(container.innerHTML).toBe('Hello <b>Jan</b>');
+  });
+
+  it('supports .has', () => {
+    const greeting = msg('Hello');
+    function Component() {
+      const t = useExtracted();
+      return String(t.has(greeting));
+    }
+    render(
+      <MockProvider>
+        <Component />
+      </MockProvider>
+    );
+    screen.getByText('true');
+  });
+});
@@ -4,6 +4,7 @@ import type {
   RichTagsFunction
 } from '../core/TranslationValues.js';
 import type {TranslateArgs} from '../core/createTranslator.js';
+import type {MessageDescriptor} from '../core/messageDescriptors.js';
 import useTranslations from './useTranslations.js';
 
 type TranslateArgsObject<
@@ -32,6 +33,7 @@ function getArgs<
         id?: string;
         message: Message;
         description?: string;
+        context?: string;
       } & TranslateArgsObject<Message, TagsFn>),
   ...rest: TranslateArgs<Message, TagsFn>
 ): [
@@ -46,9 +48,10 @@ function getArgs<
     formats = rest[1];
   } else {
     message = messageOrParams.message;
-    values = messageOrParams.values;
-    formats = messageOrParams.formats;
-    // `description` is not used at runtime
+    // Descriptors receive values as separate arguments
+    values = messageOrParams.values ?? rest[0];
+    formats = messageOrParams.formats ?? rest[1];
+    // `description` and `context` are not used at runtime
   }
   // @ts-expect-error -- Secret fallback parameter
   return [
@@ -87,8 +90,15 @@ export default function useExtracted(namespace?: string) {
       message: Message;
       /** Description for translators and tooling. */
       description?: string;
+      /** Distinguishes the same message with different meanings. */
+      context?: string;
     } & TranslateArgsObject<Message>
   ): string;
+  function translateFn<Message extends string>(
+    /** Message defined via `msg` or `defineMessages`. */
+    descriptor: MessageDescriptor<Message>,
+    ...[values, formats]: TranslateArgs<Message>
+  ): string;
   function translateFn(...params: Parameters<typeof getArgs>): string {
     // @ts-expect-error -- Passing `undefined` as an ID is secretly allowed here
     return t(...getArgs(...params));
@@ -108,8 +118,15 @@ export default function useExtracted(namespace?: string) {
         message: Message;
         /** Description for translators and tooling. */
         description?: string;
+        /** Distinguishes the same message with different meanings. */
+        context?: string;
       } & TranslateArgsObject<Message, RichTagsFunction>
     ): ReactNode;
+    <Message extends string>(
+      /** Message defined via `msg` or `defineMessages`. */
+      descriptor: MessageDescriptor<Message>,
+      ...[values, formats]: TranslateArgs<Message, RichTagsFunction>
+    ): ReactNode;
   };
 
   translateFn.markup = ((...params: Parameters<typeof getArgs>): string =>
@@ -126,13 +143,20 @@ export default function useExtracted(namespace?: string) {
         message: Message;
         /** Description for translators and tooling. */
         description?: string;
+        /** Distinguishes the same message with different meanings. */
+        context?: string;
       } & TranslateArgsObject<Message, MarkupTagsFunction>
     ): string;
+    <Message extends string>(
+      /** Message defined via `msg` or `defineMessages`. */
+      descriptor: MessageDescriptor<Message>,
+      ...[values, formats]: TranslateArgs<Message, MarkupTagsFunction>
+    ): string;
   };
 
   translateFn.has = function translateHasFn<Message extends string>(
     // eslint-disable-next-line @typescript-eslint/no-unused-vars
-    message: Message
+    message: Message | MessageDescriptor<Message>
   ): boolean {
     // Not really something better we can do here
     return true;